@@ -72,12 +72,11 @@ fn main() -> ! {
         clocks,
     );
 
-    let interface = display_interface_spi::SPIInterfaceNoCS::new(spi, dc);
+    let interface = SPIInterfaceNoCS::new(spi, dc);
     let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
         .into_buffered_graphics_mode();
 
-    display.reset(&mut rst, &mut delay).unwrap();
-    display.init().unwrap();
+    display.init_with_reset(&mut rst, &mut delay).unwrap();
 
     let yoffset = 20;
 
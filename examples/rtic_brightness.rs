@@ -8,7 +8,6 @@
 
 #[rtic::app(device = stm32f1xx_hal::pac, peripherals = true, dispatchers = [EXTI0])]
 mod app {
-    use display_interface_spi::SPIInterfaceNoCS;
     use embedded_graphics::{
         geometry::Point,
         image::Image,
@@ -100,12 +99,11 @@ mod app {
             clocks,
         );
 
-        let interface = display_interface_spi::SPIInterfaceNoCS::new(spi, dc);
+        let interface = SPIInterfaceNoCS::new(spi, dc);
         let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate180)
             .into_buffered_graphics_mode();
 
-        display.reset(&mut rst, &mut delay).unwrap();
-        display.init().unwrap();
+        display.init_with_reset(&mut rst, &mut delay).unwrap();
 
         // Update framerate
         let mut timer = dp.TIM1.counter_ms(&clocks);
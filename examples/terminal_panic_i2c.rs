@@ -0,0 +1,112 @@
+//! Show panic messages on the display, then count until the counter overflows and panics.
+//!
+//! This example is for the STM32F103 "Blue Pill" board using I2C1.
+//!
+//! Wiring connections are as follows for a CRIUS-branded display:
+//!
+//! ```
+//!      Display -> Blue Pill
+//! (black)  GND -> GND
+//! (red)    +5V -> VCC
+//! (yellow) SDA -> PB9
+//! (green)  SCL -> PB8
+//! ```
+//!
+//! Run on a Blue Pill with `cargo run --example terminal_panic_i2c --features panic-screen`.
+
+#![no_std]
+#![no_main]
+
+use core::{fmt::Write, panic::PanicInfo};
+use cortex_m_rt::{entry, exception, ExceptionFrame};
+use ssd1306::{panic_screen, prelude::*, I2CDisplayInterface, Ssd1306};
+use stm32f1xx_hal::{
+    gpio,
+    i2c::{BlockingI2c, DutyCycle, Mode},
+    pac::I2C1,
+    prelude::*,
+    stm32,
+};
+
+type Display = Ssd1306<
+    I2CInterface<
+        BlockingI2c<
+            I2C1,
+            (
+                gpio::gpiob::PB8<gpio::Alternate<gpio::OpenDrain>>,
+                gpio::gpiob::PB9<gpio::Alternate<gpio::OpenDrain>>,
+            ),
+        >,
+    >,
+    DisplaySize128x64,
+    TerminalMode,
+>;
+
+#[entry]
+fn main() -> ! {
+    let dp = stm32::Peripherals::take().unwrap();
+
+    let mut flash = dp.FLASH.constrain();
+    let rcc = dp.RCC.constrain();
+
+    let clocks = rcc.cfgr.freeze(&mut flash.acr);
+
+    let mut afio = dp.AFIO.constrain();
+
+    let mut gpiob = dp.GPIOB.split();
+
+    let scl = gpiob.pb8.into_alternate_open_drain(&mut gpiob.crh);
+    let sda = gpiob.pb9.into_alternate_open_drain(&mut gpiob.crh);
+
+    let i2c = BlockingI2c::i2c1(
+        dp.I2C1,
+        (scl, sda),
+        &mut afio.mapr,
+        Mode::Fast {
+            frequency: 400_000.Hz(),
+            duty_cycle: DutyCycle::Ratio2to1,
+        },
+        clocks,
+        1000,
+        10,
+        1000,
+        1000,
+    );
+
+    let interface = I2CDisplayInterface::new(i2c);
+    let display: &'static mut Display = cortex_m::singleton!(: Display =
+        Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0).into_terminal_mode()
+    )
+    .unwrap();
+    display.init().unwrap();
+    let _ = display.clear();
+
+    panic_screen::set_panic_display(display);
+
+    let mut counter: u8 = 0;
+
+    loop {
+        panic_screen::with_panic_display(|display| {
+            let _ = write!(display, "{} ", counter);
+        });
+
+        // Panics with "attempt to add with overflow" in debug builds
+        counter += 1;
+
+        cortex_m::asm::delay(1_000_000);
+    }
+}
+
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    panic_screen::display_panic(info);
+
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
+#[exception]
+unsafe fn HardFault(ef: &ExceptionFrame) -> ! {
+    panic!("{:#?}", ef);
+}
@@ -0,0 +1,230 @@
+//! Playing animations stored in flash.
+//!
+//! An [`Animation`] shows a sequence of [`Frame`]s in the layout of the display memory, for
+//! example a boot animation or an idle loop stored in a `static`, on a display in
+//! [basic mode](crate::mode::BasicMode). Each frame is sent straight from flash, so no buffer is
+//! needed. Frames can be [run-length encoded](Frame::rle) to save flash.
+//!
+//! [`play`](Animation::play) blocks until the animation has been shown, using a delay provider.
+//! Alternatively, [`update`](Animation::update) can be called from the main loop with the
+//! current time in milliseconds, and shows the next frame once the current one is due.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::{DelayStub, StubInterface};
+//! # let interface = StubInterface;
+//! # let mut delay = DelayStub;
+//! # let now = 0;
+//! use ssd1306::{
+//!     animation::{Animation, Frame},
+//!     prelude::*,
+//!     Ssd1306,
+//! };
+//!
+//! static BLINK_ON: [u8; 1024] = [0xff; 1024];
+//! static BLINK_OFF: [u8; 1024] = [0x00; 1024];
+//! static BLINK: [Frame; 2] = [
+//!     Frame::new(&BLINK_ON, 500),
+//!     Frame::new(&BLINK_OFF, 500),
+//! ];
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+//! display.init().unwrap();
+//!
+//! // Blink once, waiting for the animation to end
+//! Animation::new(&BLINK).play(&mut display, &mut delay).unwrap();
+//!
+//! // Blink forever without blocking the main loop
+//! let mut animation = Animation::new(&BLINK).with_looping(true);
+//!
+//! loop {
+//!     animation.update(now, &mut display).unwrap();
+//! #   break;
+//! }
+//! ```
+
+use crate::{error::DriverError, mode::BasicMode, size::DisplaySize, Ssd1306};
+use display_interface::WriteOnlyDataCommand;
+use embedded_hal::blocking::delay::DelayMs;
+
+/// A frame of an [`Animation`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Frame<'a> {
+    data: &'a [u8],
+    duration_ms: u16,
+    /// The data is [run-length encoded](crate::rle)
+    rle: bool,
+}
+
+impl<'a> Frame<'a> {
+    /// Create a frame which is shown for `duration_ms` milliseconds.
+    ///
+    /// `data` is in the layout of the display memory, as taken by
+    /// [`draw_frame`](Ssd1306::draw_frame).
+    pub const fn new(data: &'a [u8], duration_ms: u16) -> Self {
+        Self {
+            data,
+            duration_ms,
+            rle: false,
+        }
+    }
+
+    /// Create a frame from [run-length encoded](crate::rle) data, which is shown for
+    /// `duration_ms` milliseconds.
+    pub const fn rle(data: &'a [u8], duration_ms: u16) -> Self {
+        Self {
+            data,
+            duration_ms,
+            rle: true,
+        }
+    }
+
+    /// Get the contents of the frame, which may be [run-length encoded](Frame::is_rle).
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Get whether the contents of the frame are [run-length encoded](crate::rle).
+    pub fn is_rle(&self) -> bool {
+        self.rle
+    }
+
+    /// Draw the frame to the display
+    fn draw<DI, SIZE>(&self, display: &mut Ssd1306<DI, SIZE, BasicMode>) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        if self.rle {
+            display.draw_rle_frame(self.data)
+        } else {
+            display.draw_frame(self.data)
+        }
+    }
+
+    /// Get how long the frame is shown, in milliseconds.
+    pub fn duration_ms(&self) -> u16 {
+        self.duration_ms
+    }
+}
+
+/// Player for a sequence of [`Frame`]s.
+///
+/// See the [module documentation](crate::animation) for an example.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Animation<'a> {
+    frames: &'a [Frame<'a>],
+    looping: bool,
+    /// Index of the next frame to show
+    next: usize,
+    /// Time at which the next frame is due, once the first frame was shown
+    due: Option<u32>,
+}
+
+impl<'a> Animation<'a> {
+    /// Create an animation which shows `frames` in order, once.
+    pub const fn new(frames: &'a [Frame<'a>]) -> Self {
+        Self {
+            frames,
+            looping: false,
+            next: 0,
+            due: None,
+        }
+    }
+
+    /// Start again from the first frame after the last one, for animations updated with
+    /// [`update`](Animation::update).
+    pub const fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Get whether the last frame has been shown for its duration.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.frames.len() && self.due.is_none()
+    }
+
+    /// Start the animation again from the first frame.
+    pub fn restart(&mut self) {
+        self.next = 0;
+        self.due = None;
+    }
+
+    /// Show the remaining frames on `display`, waiting for the duration of each frame. The
+    /// animation is played once, even if it's looping.
+    pub fn play<DI, SIZE, DELAY>(
+        &mut self,
+        display: &mut Ssd1306<DI, SIZE, BasicMode>,
+        delay: &mut DELAY,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+        DELAY: DelayMs<u8>,
+    {
+        while let Some(frame) = self.frames.get(self.next) {
+            frame.draw(display)?;
+            self.next += 1;
+
+            let mut remaining = frame.duration_ms;
+            while remaining > 0 {
+                let ms = remaining.min(u8::MAX.into());
+                delay.delay_ms(ms as u8);
+                remaining -= ms;
+            }
+        }
+
+        self.due = None;
+
+        Ok(())
+    }
+
+    /// Show the next frame on `display` if the current one has been shown for its duration at
+    /// timestamp `now`, in milliseconds. Timestamps may wrap around. The first call shows the
+    /// first frame.
+    ///
+    /// Returns `true` while the animation is running.
+    pub fn update<DI, SIZE>(
+        &mut self,
+        now: u32,
+        display: &mut Ssd1306<DI, SIZE, BasicMode>,
+    ) -> Result<bool, DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        // Timestamps are compared as a difference, which may wrap around
+        if let Some(due) = self.due {
+            if (now.wrapping_sub(due) as i32) < 0 {
+                return Ok(true);
+            }
+        }
+
+        if self.next >= self.frames.len() && self.looping {
+            self.next = 0;
+        }
+
+        match self.frames.get(self.next) {
+            Some(frame) => {
+                frame.draw(display)?;
+                self.next += 1;
+
+                // Frames are timed from when they were due, so late updates don't add up, unless
+                // the update is so late that the frame would be skipped
+                let start = match self.due {
+                    Some(due) if now.wrapping_sub(due) < frame.duration_ms.into() => due,
+                    _ => now,
+                };
+                self.due = Some(start.wrapping_add(frame.duration_ms.into()));
+
+                Ok(true)
+            }
+            None => {
+                self.due = None;
+
+                Ok(false)
+            }
+        }
+    }
+}
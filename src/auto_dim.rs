@@ -0,0 +1,104 @@
+//! Dimming the display after a period of inactivity.
+//!
+//! [`AutoDim`] lowers the brightness of the display once nothing was drawn for a while, and
+//! restores it as soon as something is drawn again. Time is measured with timestamps provided by
+//! the application, in any unit, for example milliseconds from a system timer.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! # let now = 0;
+//! use ssd1306::{auto_dim::AutoDim, prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Dim after 30 seconds without drawing, with timestamps in milliseconds
+//! let mut auto_dim = AutoDim::new(30_000, Brightness::DIMMEST);
+//!
+//! loop {
+//! #   let changed = true;
+//!     if changed {
+//!         display.flush().unwrap();
+//!         auto_dim.activity(now, &mut display).unwrap();
+//!     }
+//!
+//!     auto_dim.update(now, &mut display).unwrap();
+//! #   break;
+//! }
+//! ```
+
+use crate::{brightness::Brightness, error::DriverError, size::DisplaySize, Ssd1306};
+use display_interface::WriteOnlyDataCommand;
+
+/// Dims the display after a period of inactivity.
+///
+/// See the [module documentation](crate::auto_dim) for an example.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AutoDim {
+    timeout: u32,
+    dimmed: Brightness,
+    last_activity: u32,
+    /// Brightness to restore while the display is dimmed
+    restore: Option<Brightness>,
+}
+
+impl AutoDim {
+    /// Create an auto dimmer which sets the display to the `dimmed` brightness once `timeout`
+    /// has passed since the last activity.
+    pub const fn new(timeout: u32, dimmed: Brightness) -> Self {
+        Self {
+            timeout,
+            dimmed,
+            last_activity: 0,
+            restore: None,
+        }
+    }
+
+    /// Get whether the display is currently dimmed.
+    pub fn is_dimmed(&self) -> bool {
+        self.restore.is_some()
+    }
+
+    /// Record activity at timestamp `now`, such as drawing to the display, and restore the
+    /// brightness if the display was dimmed.
+    pub fn activity<DI, SIZE, MODE>(
+        &mut self,
+        now: u32,
+        display: &mut Ssd1306<DI, SIZE, MODE>,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        self.last_activity = now;
+
+        if let Some(brightness) = self.restore.take() {
+            display.set_brightness(brightness)?;
+        }
+
+        Ok(())
+    }
+
+    /// Dim the display if the timeout has passed since the last activity at timestamp `now`.
+    /// Timestamps may wrap around.
+    pub fn update<DI, SIZE, MODE>(
+        &mut self,
+        now: u32,
+        display: &mut Ssd1306<DI, SIZE, MODE>,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        if self.restore.is_none() && now.wrapping_sub(self.last_activity) >= self.timeout {
+            let brightness = display.save_state().brightness();
+            display.set_brightness(self.dimmed)?;
+            self.restore = Some(brightness);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+//! Software I2C and SPI buses on GPIO pins.
+//!
+//! When the I2C and SPI peripherals of a microcontroller are already taken, or the display is
+//! connected to pins without them, [`SoftwareI2c`] and [`SoftwareSpi`] drive the bus by toggling
+//! output pins. They implement the blocking bus traits of `embedded-hal`, so they are used with
+//! the usual interfaces of the driver. This module is enabled by the `bitbang` feature, which
+//! needs the `unproven` feature of `embedded-hal` to read the data pin of the I2C bus.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::{DelayStub, PinStub};
+//! # let (scl, sda, delay) = (PinStub, PinStub, DelayStub);
+//! use ssd1306::{bitbang::SoftwareI2c, prelude::*, I2CDisplayInterface, Ssd1306};
+//!
+//! let i2c = SoftwareI2c::new(scl, sda, delay);
+//! let interface = I2CDisplayInterface::new(i2c);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//! ```
+//!
+//! The bit-banged buses are much slower than the peripherals, so a full frame takes several
+//! milliseconds to send. Flushing only the changed parts of the buffer, as the buffered graphics
+//! mode does, helps a lot.
+
+use embedded_hal::{
+    blocking::{delay::DelayUs, i2c, spi},
+    digital::v2::{InputPin, OutputPin},
+};
+
+use crate::status::AcknowledgeError;
+
+/// Errors of the software buses
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BitBangError {
+    /// Setting or reading a pin failed
+    Pin,
+    /// The I2C device didn't acknowledge a byte
+    NoAcknowledge,
+}
+
+impl AcknowledgeError for BitBangError {
+    fn is_no_acknowledge(&self) -> bool {
+        *self == BitBangError::NoAcknowledge
+    }
+}
+
+/// A software I2C bus on a clock and an open-drain data pin.
+///
+/// The data pin has to be configured as an open-drain output with a pull-up which can be read
+/// back, so that the acknowledgements of the display can be received. The bus runs at about
+/// 100kHz by default.
+///
+/// See the [module level documentation](crate::bitbang) for an example.
+#[derive(Debug, Copy, Clone)]
+pub struct SoftwareI2c<SCL, SDA, DELAY> {
+    scl: SCL,
+    sda: SDA,
+    delay: DELAY,
+    half_period: u8,
+}
+
+impl<SCL, SDA, DELAY> SoftwareI2c<SCL, SDA, DELAY>
+where
+    SCL: OutputPin,
+    SDA: OutputPin + InputPin,
+    DELAY: DelayUs<u8>,
+{
+    /// Create a new software I2C bus.
+    pub fn new(scl: SCL, sda: SDA, delay: DELAY) -> Self {
+        Self {
+            scl,
+            sda,
+            delay,
+            half_period: 5,
+        }
+    }
+
+    /// Set the time in microseconds between the edges of the clock, which is half its period.
+    pub fn with_half_period(self, half_period: u8) -> Self {
+        Self {
+            half_period,
+            ..self
+        }
+    }
+
+    /// Release the contained pins and delay.
+    pub fn release(self) -> (SCL, SDA, DELAY) {
+        (self.scl, self.sda, self.delay)
+    }
+
+    fn wait(&mut self) {
+        self.delay.delay_us(self.half_period);
+    }
+
+    fn set_scl(&mut self, high: bool) -> Result<(), BitBangError> {
+        let result = if high {
+            self.scl.set_high()
+        } else {
+            self.scl.set_low()
+        };
+
+        result.map_err(|_| BitBangError::Pin)
+    }
+
+    fn set_sda(&mut self, high: bool) -> Result<(), BitBangError> {
+        let result = if high {
+            self.sda.set_high()
+        } else {
+            self.sda.set_low()
+        };
+
+        result.map_err(|_| BitBangError::Pin)
+    }
+
+    fn start(&mut self) -> Result<(), BitBangError> {
+        self.set_sda(true)?;
+        self.set_scl(true)?;
+        self.wait();
+        self.set_sda(false)?;
+        self.wait();
+        self.set_scl(false)
+    }
+
+    fn stop(&mut self) -> Result<(), BitBangError> {
+        self.set_sda(false)?;
+        self.wait();
+        self.set_scl(true)?;
+        self.wait();
+        self.set_sda(true)?;
+        self.wait();
+
+        Ok(())
+    }
+
+    /// Clock out a bit, or clock in a bit with the data line released.
+    fn clock_bit(&mut self, bit: bool) -> Result<bool, BitBangError> {
+        self.set_sda(bit)?;
+        self.wait();
+        self.set_scl(true)?;
+        self.wait();
+        let level = self.sda.is_high().map_err(|_| BitBangError::Pin)?;
+        self.set_scl(false)?;
+
+        Ok(level)
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<(), BitBangError> {
+        for bit in (0..8).rev() {
+            self.clock_bit(byte & (1 << bit) != 0)?;
+        }
+
+        // The device pulls the data line low to acknowledge the byte
+        if self.clock_bit(true)? {
+            Err(BitBangError::NoAcknowledge)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_byte(&mut self, ack: bool) -> Result<u8, BitBangError> {
+        let mut byte = 0;
+        for _ in 0..8 {
+            byte = byte << 1 | u8::from(self.clock_bit(true)?);
+        }
+        self.clock_bit(!ack)?;
+
+        Ok(byte)
+    }
+
+    /// Run a transfer between a start and a stop condition, sending the stop even if it fails.
+    fn transfer(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<(), BitBangError>,
+    ) -> Result<(), BitBangError> {
+        self.start()?;
+        let result = f(self);
+        self.stop()?;
+
+        result
+    }
+}
+
+impl<SCL, SDA, DELAY> i2c::Write for SoftwareI2c<SCL, SDA, DELAY>
+where
+    SCL: OutputPin,
+    SDA: OutputPin + InputPin,
+    DELAY: DelayUs<u8>,
+{
+    type Error = BitBangError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), BitBangError> {
+        self.transfer(|bus| {
+            bus.write_byte(address << 1)?;
+            bytes.iter().try_for_each(|&byte| bus.write_byte(byte))
+        })
+    }
+}
+
+impl<SCL, SDA, DELAY> i2c::WriteIter for SoftwareI2c<SCL, SDA, DELAY>
+where
+    SCL: OutputPin,
+    SDA: OutputPin + InputPin,
+    DELAY: DelayUs<u8>,
+{
+    type Error = BitBangError;
+
+    fn write<B>(&mut self, address: u8, bytes: B) -> Result<(), BitBangError>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.transfer(|bus| {
+            bus.write_byte(address << 1)?;
+            bytes.into_iter().try_for_each(|byte| bus.write_byte(byte))
+        })
+    }
+}
+
+impl<SCL, SDA, DELAY> i2c::Read for SoftwareI2c<SCL, SDA, DELAY>
+where
+    SCL: OutputPin,
+    SDA: OutputPin + InputPin,
+    DELAY: DelayUs<u8>,
+{
+    type Error = BitBangError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), BitBangError> {
+        self.transfer(|bus| {
+            bus.write_byte(address << 1 | 1)?;
+
+            // Every byte but the last is acknowledged
+            let len = buffer.len();
+            for (i, byte) in buffer.iter_mut().enumerate() {
+                *byte = bus.read_byte(i + 1 < len)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// A software SPI bus on a clock and a data output pin, in SPI mode 0.
+///
+/// The SSD1306 accepts clocks of up to 10MHz, so the clock runs as fast as the pins can be
+/// toggled by default.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::{DelayStub, PinStub};
+/// # let (sck, mosi, dc, delay) = (PinStub, PinStub, PinStub, DelayStub);
+/// use ssd1306::{bitbang::SoftwareSpi, prelude::*, Ssd1306};
+///
+/// let spi = SoftwareSpi::new(sck, mosi, delay);
+/// let interface = SPIInterfaceNoCS::new(spi, dc);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct SoftwareSpi<SCK, MOSI, DELAY> {
+    sck: SCK,
+    mosi: MOSI,
+    delay: DELAY,
+    half_period: u8,
+}
+
+impl<SCK, MOSI, DELAY> SoftwareSpi<SCK, MOSI, DELAY>
+where
+    SCK: OutputPin,
+    MOSI: OutputPin,
+    DELAY: DelayUs<u8>,
+{
+    /// Create a new software SPI bus.
+    pub fn new(sck: SCK, mosi: MOSI, delay: DELAY) -> Self {
+        Self {
+            sck,
+            mosi,
+            delay,
+            half_period: 0,
+        }
+    }
+
+    /// Set the time in microseconds between the edges of the clock, which is half its period.
+    pub fn with_half_period(self, half_period: u8) -> Self {
+        Self {
+            half_period,
+            ..self
+        }
+    }
+
+    /// Release the contained pins and delay.
+    pub fn release(self) -> (SCK, MOSI, DELAY) {
+        (self.sck, self.mosi, self.delay)
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<(), BitBangError> {
+        for bit in (0..8).rev() {
+            let result = if byte & (1 << bit) != 0 {
+                self.mosi.set_high()
+            } else {
+                self.mosi.set_low()
+            };
+            result.map_err(|_| BitBangError::Pin)?;
+
+            // The display samples the data on the rising edge of the clock
+            self.delay.delay_us(self.half_period);
+            self.sck.set_high().map_err(|_| BitBangError::Pin)?;
+            self.delay.delay_us(self.half_period);
+            self.sck.set_low().map_err(|_| BitBangError::Pin)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<SCK, MOSI, DELAY> spi::Write<u8> for SoftwareSpi<SCK, MOSI, DELAY>
+where
+    SCK: OutputPin,
+    MOSI: OutputPin,
+    DELAY: DelayUs<u8>,
+{
+    type Error = BitBangError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), BitBangError> {
+        self.sck.set_low().map_err(|_| BitBangError::Pin)?;
+
+        words.iter().try_for_each(|&byte| self.write_byte(byte))
+    }
+}
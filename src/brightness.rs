@@ -2,6 +2,7 @@
 
 /// Struct that holds display brightness
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Brightness {
     pub(crate) precharge: u8,
     pub(crate) contrast: u8,
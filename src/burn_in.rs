@@ -0,0 +1,256 @@
+//! OLED burn-in mitigation.
+//!
+//! Pixels of OLED displays age with use, so content which is shown for a long time, such as the
+//! frame of an always-on UI, can burn in. [`BurnInMitigation`] reduces this by periodically
+//! shifting the image on the display up and down by one row, and by briefly inverting the
+//! display. Both are done with display commands, so the buffer isn't touched.
+//!
+//! The shift only moves the image vertically. The display can only move its columns by rewriting
+//! the display memory, so there is no horizontal shift.
+//!
+//! The application calls [`tick`](BurnInMitigation::tick) at a regular interval, for example once
+//! per second, and sets the intervals in ticks.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use ssd1306::{burn_in::BurnInMitigation, prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Ticks once per second: shift every minute and invert for 2 seconds every hour
+//! let mut burn_in = BurnInMitigation::new().shift_every(60).invert_every(3600, 2);
+//!
+//! loop {
+//!     // Draw and flush the display as usual...
+//!
+//!     burn_in.tick(&mut display).unwrap();
+//! #   break;
+//! }
+//! ```
+//!
+//! On panels with fewer rows than the display memory, such as 128x32 modules, the row shifted
+//! into view comes from the display memory outside the panel, which isn't initialised. Call
+//! [`prepare`](BurnInMitigation::prepare) once after initialising the display to clear these
+//! rows, so that a blank row is shown instead:
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use ssd1306::{burn_in::BurnInMitigation, prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! let mut burn_in = BurnInMitigation::new().shift_every(60);
+//! burn_in.prepare(&mut display).unwrap();
+//! ```
+
+use crate::{command::AddrMode, error::DriverError, size::DisplaySize, Ssd1306};
+use display_interface::WriteOnlyDataCommand;
+
+/// Number of steps of a shift cycle: unshifted, up by one row, unshifted and down by one row
+const SHIFT_STEPS: usize = 4;
+
+/// Burn-in mitigation driven by the application calling [`tick`](BurnInMitigation::tick).
+///
+/// Shifting uses the display start line, so it can't be combined with
+/// [page flipping](Ssd1306::set_page_flipping) or other uses of
+/// [`set_start_line`](Ssd1306::set_start_line). The start line wraps around the rows of the
+/// display memory, so on panels as tall as the display memory the row shifted off one edge of
+/// the display is shown at the other edge, and content should leave a blank border of one row.
+/// On shorter panels a row of the display memory outside the panel is shown instead, which
+/// [`prepare`](Self::prepare) clears.
+///
+/// See the [module documentation](crate::burn_in) for an example.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BurnInMitigation {
+    shift_interval: u32,
+    invert_interval: u32,
+    invert_duration: u32,
+    ticks: u32,
+    shift_step: usize,
+    /// Tick the display was inverted at, and whether it was inverted before
+    inverted: Option<(u32, bool)>,
+}
+
+impl Default for BurnInMitigation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BurnInMitigation {
+    /// Create a burn-in mitigation which doesn't do anything until shifting or inversion is
+    /// enabled.
+    pub const fn new() -> Self {
+        Self {
+            shift_interval: 0,
+            invert_interval: 0,
+            invert_duration: 0,
+            ticks: 0,
+            shift_step: 0,
+            inverted: None,
+        }
+    }
+
+    /// Shift the image by one row every `interval` ticks, cycling through moving it up,
+    /// back, down and back. An interval of 0 disables shifting.
+    pub const fn shift_every(mut self, interval: u32) -> Self {
+        self.shift_interval = interval;
+        self
+    }
+
+    /// Invert the display for `duration` ticks every `interval` ticks. An interval of 0 disables
+    /// inversion.
+    pub const fn invert_every(mut self, interval: u32, duration: u32) -> Self {
+        self.invert_interval = interval;
+        self.invert_duration = duration;
+        self
+    }
+
+    /// Clear the rows of the display memory which aren't part of the image, so that shifting
+    /// shows a blank row on panels with fewer rows than the display memory. Rows in the same page
+    /// of the display memory as a row of the image are left as they are.
+    ///
+    /// Call this once after initialising the display, before drawing. It changes the position of
+    /// the display's internal draw pointer, so in terminal mode it must be called before the
+    /// display is cleared. Nothing is sent for panels as tall as the display memory.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "test-util")] {
+    /// use ssd1306::{burn_in::BurnInMitigation, mock::MockInterface, prelude::*, Ssd1306};
+    ///
+    /// let interface = MockInterface::new(DisplaySize128x32);
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    ///
+    /// // Leave garbage in all of the display memory, as after power up
+    /// display.send_raw_command(&[0x20, 0x00, 0x21, 0, 127, 0x22, 0, 7]).unwrap();
+    /// display.draw(&[0xff; 1024]).unwrap();
+    ///
+    /// display.init().unwrap();
+    /// display.set_pixel(5, 31, true);
+    /// display.flush().unwrap();
+    ///
+    /// let mut burn_in = BurnInMitigation::new().shift_every(1);
+    /// burn_in.prepare(&mut display).unwrap();
+    ///
+    /// // Shifted up by one row, with a blank row at the bottom
+    /// burn_in.tick(&mut display).unwrap();
+    /// let panel = display.interface().simulator();
+    /// assert!(panel.pixel(5, 30));
+    /// assert!((0..128).all(|x| !panel.pixel(x, 31)));
+    ///
+    /// // Shifted down by one row, with a blank row at the top
+    /// burn_in.tick(&mut display).unwrap();
+    /// burn_in.tick(&mut display).unwrap();
+    /// let panel = display.interface().simulator();
+    /// assert!((0..128).all(|x| !panel.pixel(x, 0)));
+    /// # }
+    /// ```
+    pub fn prepare<DI, SIZE, MODE>(
+        &self,
+        display: &mut Ssd1306<DI, SIZE, MODE>,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        let image = display.offset_y..display.offset_y + SIZE::HEIGHT;
+        let mut pages = (0..SIZE::DRIVER_ROWS / 8)
+            .filter(|page| image.end <= page * 8 || page * 8 + 8 <= image.start)
+            .peekable();
+
+        if pages.peek().is_none() {
+            return Ok(());
+        }
+
+        let old_addr_mode = display.addr_mode;
+        if old_addr_mode != AddrMode::Horizontal {
+            display.set_addr_mode(AddrMode::Horizontal)?;
+        }
+
+        let zeros = [0; 128];
+        for page in pages {
+            display.set_draw_area((0, page * 8), (SIZE::DRIVER_COLS, page * 8 + 8))?;
+            display.draw(&zeros[..usize::from(SIZE::DRIVER_COLS)])?;
+        }
+
+        if old_addr_mode != AddrMode::Horizontal {
+            display.set_addr_mode(old_addr_mode)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the start line of a step of the shift cycle.
+    fn start_line<SIZE: DisplaySize>(step: usize) -> u8 {
+        match step {
+            1 => 1,
+            // The start line wraps around the rows of the display memory
+            3 => SIZE::DRIVER_ROWS - 1,
+            _ => 0,
+        }
+    }
+
+    /// Advance by one tick, shifting or inverting the display when an interval has passed.
+    pub fn tick<DI, SIZE, MODE>(
+        &mut self,
+        display: &mut Ssd1306<DI, SIZE, MODE>,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        self.ticks = self.ticks.wrapping_add(1);
+
+        if self.shift_interval > 0 && self.ticks % self.shift_interval == 0 {
+            self.shift_step = (self.shift_step + 1) % SHIFT_STEPS;
+            display.set_start_line(Self::start_line::<SIZE>(self.shift_step))?;
+        }
+
+        match self.inverted {
+            Some((start, invert)) => {
+                if self.ticks.wrapping_sub(start) >= self.invert_duration {
+                    display.set_invert(invert)?;
+                    self.inverted = None;
+                }
+            }
+            None => {
+                if self.invert_interval > 0 && self.ticks % self.invert_interval == 0 {
+                    let invert = display.save_state().invert();
+                    display.set_invert(!invert)?;
+                    self.inverted = Some((self.ticks, invert));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Undo any shift or inversion, for example before turning the mitigation off.
+    pub fn reset<DI, SIZE, MODE>(
+        &mut self,
+        display: &mut Ssd1306<DI, SIZE, MODE>,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        SIZE: DisplaySize,
+    {
+        if self.shift_step != 0 {
+            display.set_start_line(0)?;
+            self.shift_step = 0;
+        }
+
+        if let Some((_, invert)) = self.inverted.take() {
+            display.set_invert(invert)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Display clock
+
+/// Struct that holds the display clock configuration, which sets the frame rate of the display
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DisplayClock {
+    pub(crate) frequency: u8,
+    pub(crate) divide_ratio: u8,
+}
+
+impl Default for DisplayClock {
+    fn default() -> Self {
+        DisplayClock::NORMAL
+    }
+}
+
+impl DisplayClock {
+    /// A predefined clock at half of the normal frame rate, which uses less power but may
+    /// flicker visibly
+    pub const SLOW: DisplayClock = DisplayClock::custom(0x8, 2);
+
+    /// The predefined clock the display is initialised with by default
+    pub const NORMAL: DisplayClock = DisplayClock::custom(0x8, 1);
+
+    /// The fastest predefined clock, which reduces flicker, for example in camera footage
+    pub const FASTEST: DisplayClock = DisplayClock::custom(0xF, 1);
+
+    /// Create a DisplayClock object from an oscillator frequency and divide ratio pair.
+    ///
+    /// `frequency` sets the oscillator frequency, which increases with higher values, and must be
+    /// between 0 and 15. The frequency of each setting is hardware dependent.
+    ///
+    /// `divide_ratio` sets the ratio the oscillator frequency is divided by to get the display
+    /// clock, and must be between 1 and 16.
+    ///
+    /// Values outside of these ranges are clamped to the nearest valid value.
+    ///
+    /// The frame rate is proportional to the oscillator frequency divided by the divide ratio.
+    /// See section 10.1.16 of the SSD1306 datasheet for more information.
+    ///
+    /// ```rust
+    /// use ssd1306::prelude::*;
+    ///
+    /// assert_eq!(DisplayClock::custom(20, 0), DisplayClock::custom(15, 1));
+    /// assert_eq!(DisplayClock::custom(8, 32), DisplayClock::custom(8, 16));
+    /// ```
+    pub const fn custom(frequency: u8, divide_ratio: u8) -> Self {
+        let frequency = if frequency > 15 { 15 } else { frequency };
+        let divide_ratio = if divide_ratio < 1 {
+            1
+        } else if divide_ratio > 16 {
+            16
+        } else {
+            divide_ratio
+        };
+
+        Self {
+            frequency,
+            divide_ratio,
+        }
+    }
+}
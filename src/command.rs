@@ -2,10 +2,14 @@
 
 // Shamefully taken from https://github.com/EdgewaterDevelopment/rust-ssd1306
 
-use display_interface::{DataFormat::U8, DisplayError, WriteOnlyDataCommand};
+use display_interface::{
+    DataFormat::{self, U8},
+    DisplayError, WriteOnlyDataCommand,
+};
 
 /// SSD1306 Commands
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Command {
     /// Set contrast. Higher number is higher contrast. Default = 0x7F
     Contrast(u8),
@@ -83,8 +87,18 @@ pub enum Command {
     Noop,
     /// Enable charge pump
     ChargePump(bool),
-    /// Select external or internal I REF. Only for 72 x 40 display with SSD1306B driver
+    /// Select external or internal I REF. Only for SSD1306B and compatible drivers, such as the
+    /// one used by 72 x 40 displays.
+    /// First value selects the external (false) or internal (true) reference. Second value
+    /// selects the lower (false) or higher (true) reference current.
     InternalIref(bool, bool),
+    /// Set fade out or blinking mode.
+    /// Values are the mode and the number of frames per contrast step,
+    /// in units of 8 frames minus one (0-15)
+    FadeBlink(FadeMode, u8),
+    /// Enable zoom in, showing every row twice. Only supported with the alternative COM pin
+    /// configuration
+    ZoomIn(bool),
 }
 
 impl Command {
@@ -155,6 +169,10 @@ impl Command {
             Command::ChargePump(en) => {
                 Self::send_commands(iface, &[0x8D, 0x10 | ((en as u8) << 2)])
             }
+            Command::FadeBlink(mode, interval) => {
+                Self::send_commands(iface, &[0x23, ((mode as u8) << 4) | (0xF & interval)])
+            }
+            Command::ZoomIn(zoom) => Self::send_commands(iface, &[0xD6, zoom as u8]),
             Command::InternalIref(en, current) => {
                 Self::send_commands(iface, &[0xAD, ((current as u8) << 5) | ((en as u8) << 4)])
             }
@@ -165,12 +183,82 @@ impl Command {
     where
         DI: WriteOnlyDataCommand,
     {
+        crate::trace::commands(data);
         iface.send_commands(U8(data))
     }
 }
 
+/// Maximum number of command bytes sent in one transfer, limited by the I2C interface
+const BATCH_SIZE: usize = 7;
+
+/// Interface adapter which packs the bytes of several commands into as few transfers as
+/// possible. Commands are sent once the next one doesn't fit, data is sent, or the batch is
+/// flushed.
+pub(crate) struct CommandBatch<'a, DI> {
+    iface: &'a mut DI,
+    buffer: [u8; BATCH_SIZE],
+    len: usize,
+}
+
+impl<'a, DI> CommandBatch<'a, DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    pub(crate) fn new(iface: &'a mut DI) -> Self {
+        Self {
+            iface,
+            buffer: [0; BATCH_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Send all collected command bytes
+    pub(crate) fn flush(&mut self) -> Result<(), DisplayError> {
+        if self.len == 0 {
+            return Ok(());
+        }
+
+        let len = core::mem::replace(&mut self.len, 0);
+        self.iface.send_commands(U8(&self.buffer[..len]))
+    }
+}
+
+impl<DI> WriteOnlyDataCommand for CommandBatch<'_, DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match cmd {
+            U8(bytes) => bytes,
+            other => {
+                self.flush()?;
+                return self.iface.send_commands(other);
+            }
+        };
+
+        if self.len + bytes.len() > BATCH_SIZE {
+            self.flush()?;
+        }
+
+        if bytes.len() > BATCH_SIZE {
+            return self.iface.send_commands(U8(bytes));
+        }
+
+        self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.flush()?;
+        self.iface.send_data(buf)
+    }
+}
+
 /// Horizontal Scroll Direction
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(dead_code)]
 pub enum HScrollDir {
     /// Left to right
@@ -181,6 +269,7 @@ pub enum HScrollDir {
 
 /// Vertical and horizontal scroll dir
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(dead_code)]
 pub enum VHScrollDir {
     /// Vertical and right horizontal
@@ -191,6 +280,7 @@ pub enum VHScrollDir {
 
 /// Display page
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Page {
     /// Page 0
     Page0 = 0b0000,
@@ -252,6 +342,7 @@ impl From<u8> for Page {
 
 /// Frame interval
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(dead_code)]
 pub enum NFrames {
     /// 2 Frames
@@ -274,6 +365,7 @@ pub enum NFrames {
 
 /// Address mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(dead_code)]
 pub enum AddrMode {
     /// Horizontal mode
@@ -284,8 +376,21 @@ pub enum AddrMode {
     Page = 0b10,
 }
 
+/// Fade out and blinking mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FadeMode {
+    /// Fade out and blinking disabled
+    Disabled = 0b00,
+    /// Gradually reduce the contrast until the display is blank
+    FadeOut = 0b10,
+    /// Repeatedly fade the contrast out and back in
+    Blink = 0b11,
+}
+
 /// Vcomh Deselect level
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(dead_code)]
 pub enum VcomhLevel {
     /// 0.65 * Vcc
@@ -1,8 +1,179 @@
+use display_interface::DisplayError;
+
 /// Errors in this crate
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<CommE, PinE> {
     /// Communication error
     Comm(CommE),
     /// Pin setting error
     Pin(PinE),
 }
+
+/// The operation of the driver during which an error occurred
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Operation {
+    /// Initialising the display
+    Init,
+    /// Flushing the buffer of a display mode
+    Flush,
+    /// Any other operation, such as changing a setting
+    Other,
+}
+
+/// The kind of transfer to the display which failed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Transfer {
+    /// Command bytes, with the opcode of the first command of the transfer if it is known
+    Command(Option<u8>),
+    /// Data bytes for the display memory
+    Data,
+}
+
+/// Where the last failed transfer of the interface occurred
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ErrorContext {
+    pub(crate) operation: Operation,
+    pub(crate) transfer: Transfer,
+}
+
+/// An interface which keeps the original error of the bus for the last failed transfer.
+///
+/// Interfaces report errors of the bus to the driver as [`DisplayError::BusWriteError`], which
+/// doesn't say what went wrong. The interfaces of this crate keep the error of the HAL, so that
+/// after the driver returned a [`DriverError`], the application can take it from the interface
+/// with [`interface_mut`](crate::Ssd1306::interface_mut) and handle specific bus failures.
+///
+/// The [`I2CInterface`](crate::I2CInterface), [`SPIInterface`](crate::SPIInterface) and
+/// [`SPIInterfaceNoCS`](crate::SPIInterfaceNoCS) of the `display-interface` crates don't keep
+/// the error. Create an I2C interface with
+/// [`I2CDisplayInterface::new_readable`](crate::I2CDisplayInterface::new_readable) or
+/// [`I2CDisplayInterface::new_continuous`](crate::I2CDisplayInterface::new_continuous) instead
+/// of [`I2CDisplayInterface::new`](crate::I2CDisplayInterface::new) to get it.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::I2cStub;
+/// # let i2c = I2cStub;
+/// use display_interface::DisplayError;
+/// use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+///
+/// let interface = I2CDisplayInterface::new_continuous(i2c);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+///
+/// if let Err(error) = display.init() {
+///     if let DisplayError::BusWriteError = error.source() {
+///         // The error of the HAL, for example an arbitration loss
+///         let bus_error = display.interface_mut().take_bus_error();
+///     }
+/// }
+/// ```
+pub trait TakeBusError {
+    /// The error of the bus
+    type BusError;
+
+    /// Take the error of the bus of the last failed transfer, if any.
+    fn take_bus_error(&mut self) -> Option<Self::BusError>;
+}
+
+/// An error of the driver, with the operation and transfer during which it occurred.
+///
+/// Errors of the interface are only reported as a [`DisplayError`] such as
+/// [`BusWriteError`](DisplayError::BusWriteError), so the driver adds what it was doing when the
+/// error occurred. The [`Debug`](core::fmt::Debug) output includes all of it, which makes errors in
+/// logs from the field easier to track down. Methods which are documented to return a
+/// [`DisplayError`] return it as the [`source`](DriverError::source) of the error.
+///
+/// The original error of the bus, such as an arbitration loss on I2C, can be taken from the
+/// interfaces of this crate with [`TakeBusError`].
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use ssd1306::{prelude::*, Operation, Ssd1306, Transfer};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+///
+/// if let Err(error) = display.init() {
+///     // For example `DriverError { operation: Init, transfer: Some(Command(Some(174))),
+///     // source: BusWriteError }`
+///     println!("{:?}", error);
+///
+///     if error.operation() == Operation::Init && error.transfer() == Some(Transfer::Data) {
+///         // The init sequence failed while sending a splash screen
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DriverError {
+    operation: Operation,
+    transfer: Option<Transfer>,
+    source: DisplayError,
+}
+
+impl DriverError {
+    /// Create an error which occurred during `operation`, adding the failed transfer if there is
+    /// one.
+    pub(crate) fn new(
+        source: DisplayError,
+        operation: Operation,
+        context: Option<ErrorContext>,
+    ) -> Self {
+        match context {
+            Some(context) => Self {
+                operation: context.operation,
+                transfer: Some(context.transfer),
+                source,
+            },
+            None => Self {
+                operation,
+                transfer: None,
+                source,
+            },
+        }
+    }
+
+    /// Get the operation of the driver during which the error occurred.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Get the transfer which failed, or `None` if the error didn't occur in a transfer, for
+    /// example because an argument was out of bounds.
+    pub fn transfer(&self) -> Option<Transfer> {
+        self.transfer
+    }
+
+    /// Get the error of the interface or the driver.
+    pub fn source(&self) -> &DisplayError {
+        &self.source
+    }
+}
+
+impl From<DisplayError> for DriverError {
+    fn from(source: DisplayError) -> Self {
+        Self::new(source, Operation::Other, None)
+    }
+}
+
+impl From<DriverError> for DisplayError {
+    fn from(error: DriverError) -> Self {
+        error.source
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DriverError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "DriverError {{ operation: {}, transfer: {}, source: {} }}",
+            self.operation,
+            self.transfer,
+            defmt::Debug2Format(&self.source)
+        )
+    }
+}
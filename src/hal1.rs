@@ -0,0 +1,484 @@
+//! Interfaces for I2C and SPI buses of `embedded-hal` 1.0.
+//!
+//! The driver is built on `embedded-hal` 0.2, but these interfaces let it use the buses of HALs
+//! which implement `embedded-hal` 1.0 directly, without adapters. They are enabled by the
+//! `embedded-hal-1` feature.
+//!
+//! As the bus traits of `embedded-hal` 1.0 support transactions, the I2C interface sends all
+//! command or data bytes of a transfer in a single transaction, instead of copying them into
+//! transfers of 16 bytes. SPI buses are used through [`SpiDevice`], which manages the chip select
+//! pin.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::I2cStub;
+//! # let i2c = I2cStub;
+//! use ssd1306::{hal1::I2cInterface, prelude::*, Ssd1306};
+//!
+//! let interface = I2cInterface::new(i2c);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//! display.flush().unwrap();
+//! ```
+//!
+//! For the async buses of `embedded-hal-async`, see the [`hal_async`](crate::hal_async) module.
+//!
+//! # Sharing the bus
+//!
+//! With the `embedded-hal-bus` feature, the interfaces can be created straight from a bus which
+//! is shared with other devices, such as sensors, through the devices of `embedded-hal-bus`.
+//! [`I2cInterface::new_shared`] and [`SpiInterface::new_shared`] share the bus in a
+//! [`RefCell`] within one thread or interrupt priority, [`I2cInterface::new_critical_section`]
+//! and [`SpiInterface::new_critical_section`] share it across interrupts, and
+//! [`I2cInterface::new_atomic`] and [`SpiInterface::new_atomic`] share it with a flag which
+//! reports a bus which is already in use as an error instead of blocking. The `embedded-hal-bus`
+//! crate needs Rust 1.81 or later.
+//!
+//! ```rust
+//! # #[cfg(feature = "embedded-hal-bus")] {
+//! # use ssd1306::test_helpers::I2cStub;
+//! # let i2c = I2cStub;
+//! use core::cell::RefCell;
+//! use embedded_hal_bus::i2c::RefCellDevice;
+//! use ssd1306::{hal1::I2cInterface, prelude::*, Ssd1306};
+//!
+//! let bus = RefCell::new(i2c);
+//!
+//! let interface = I2cInterface::new_shared(&bus);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Other devices on the bus, which would be passed to their drivers
+//! let sensor = RefCellDevice::new(&bus);
+//! # }
+//! ```
+
+use core::fmt;
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_hal_1::{
+    digital::OutputPin,
+    i2c::{Error as _, ErrorKind, I2c, Operation},
+    spi::SpiDevice,
+};
+
+#[cfg(feature = "embedded-hal-bus")]
+use core::cell::RefCell;
+#[cfg(feature = "embedded-hal-bus")]
+use critical_section::Mutex;
+#[cfg(all(feature = "embedded-hal-bus", target_has_atomic = "8"))]
+use embedded_hal_bus::util::AtomicCell;
+#[cfg(feature = "embedded-hal-bus")]
+use embedded_hal_bus::{i2c, spi};
+
+use crate::{
+    error::TakeBusError,
+    status::{DetectNoAcknowledge, ReadStatus},
+};
+
+/// An interface for an I2C bus of `embedded-hal` 1.0.
+///
+/// Errors of the I2C bus are returned as [`DisplayError::BusWriteError`] by the driver, but the
+/// original error of the last failed transfer is kept, and can be taken with
+/// [`take_bus_error`](TakeBusError::take_bus_error).
+///
+/// See the [module level documentation](crate::hal1) for an example.
+pub struct I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType,
+{
+    i2c: I2C,
+    address: u8,
+    bus_error: Option<I2C::Error>,
+}
+
+impl<I2C> I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    /// Create a new I2C interface with the default address of 0x3C.
+    pub fn new(i2c: I2C) -> Self {
+        Self::new_custom_address(i2c, 0x3C)
+    }
+
+    /// Create a new I2C interface with the alternate address 0x3D as specified in the datasheet.
+    pub fn new_alternate_address(i2c: I2C) -> Self {
+        Self::new_custom_address(i2c, 0x3D)
+    }
+
+    /// Create a new I2C interface with a custom address.
+    pub fn new_custom_address(i2c: I2C, address: u8) -> Self {
+        Self {
+            i2c,
+            address,
+            bus_error: None,
+        }
+    }
+
+    /// Release the contained I2C bus.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Keep the error of a transfer, and flatten it into a [`DisplayError`].
+    fn keep_error<T>(&mut self, result: Result<T, I2C::Error>) -> Result<T, DisplayError> {
+        result.map_err(|error| {
+            self.bus_error = Some(error);
+            DisplayError::BusWriteError
+        })
+    }
+
+    /// Write `bytes` preceded by the `control` byte, in a single transaction.
+    fn write(&mut self, control: u8, format: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match format {
+            DataFormat::U8(bytes) => bytes,
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        let result = self.i2c.transaction(
+            self.address,
+            &mut [Operation::Write(&[control]), Operation::Write(bytes)],
+        );
+        self.keep_error(result)
+    }
+}
+
+impl<I2C> TakeBusError for I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType,
+{
+    type BusError = I2C::Error;
+
+    fn take_bus_error(&mut self) -> Option<I2C::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<I2C> DetectNoAcknowledge for I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType,
+{
+    fn no_acknowledge(&self) -> bool {
+        self.bus_error.as_ref().map_or(false, |error| {
+            matches!(error.kind(), ErrorKind::NoAcknowledge(_))
+        })
+    }
+}
+
+impl<I2C> fmt::Debug for I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("I2cInterface")
+            .field("i2c", &self.i2c)
+            .field("address", &self.address)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<I2C> Clone for I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + Clone,
+    I2C::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            i2c: self.i2c.clone(),
+            address: self.address,
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<I2C> Copy for I2cInterface<I2C>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + Copy,
+    I2C::Error: Copy,
+{
+}
+
+impl<I2C> WriteOnlyDataCommand for I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        // Command bytes are preceded by a control byte with D/C# cleared
+        self.write(0x00, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        // Data bytes are preceded by a control byte with D/C# set
+        self.write(0x40, buf)
+    }
+}
+
+impl<I2C> ReadStatus for I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    fn read_status(&mut self) -> Result<u8, DisplayError> {
+        let mut status = [0];
+
+        let result = self.i2c.read(self.address, &mut status);
+        self.keep_error(result)?;
+
+        Ok(status[0])
+    }
+}
+
+/// An interface for an SPI device of `embedded-hal` 1.0 and a data/command pin.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::{PinStub, SpiStub};
+/// # let (spi, dc) = (SpiStub, PinStub);
+/// use ssd1306::{hal1::SpiInterface, prelude::*, Ssd1306};
+///
+/// let interface = SpiInterface::new(spi, dc);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// ```
+///
+/// Errors of the SPI device are returned as [`DisplayError::BusWriteError`] by the driver, but
+/// the original error of the last failed transfer is kept, and can be taken with
+/// [`take_bus_error`](TakeBusError::take_bus_error).
+pub struct SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType,
+{
+    spi: SPI,
+    dc: DC,
+    bus_error: Option<SPI::Error>,
+}
+
+impl<SPI, DC> SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    /// Create a new SPI interface.
+    pub fn new(spi: SPI, dc: DC) -> Self {
+        Self {
+            spi,
+            dc,
+            bus_error: None,
+        }
+    }
+
+    /// Release the contained SPI device and data/command pin.
+    pub fn release(self) -> (SPI, DC) {
+        (self.spi, self.dc)
+    }
+
+    /// Write `bytes` with the data/command pin set to `data`.
+    fn write(&mut self, data: bool, format: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match format {
+            DataFormat::U8(bytes) => bytes,
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        let dc = if data {
+            self.dc.set_high()
+        } else {
+            self.dc.set_low()
+        };
+        dc.map_err(|_| DisplayError::DCError)?;
+
+        self.spi.write(bytes).map_err(|error| {
+            self.bus_error = Some(error);
+            DisplayError::BusWriteError
+        })
+    }
+}
+
+impl<SPI, DC> TakeBusError for SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType,
+{
+    type BusError = SPI::Error;
+
+    fn take_bus_error(&mut self) -> Option<SPI::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<SPI, DC> DetectNoAcknowledge for SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType,
+{
+    fn no_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+impl<SPI, DC> fmt::Debug for SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType + fmt::Debug,
+    DC: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpiInterface")
+            .field("spi", &self.spi)
+            .field("dc", &self.dc)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<SPI, DC> Clone for SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType + Clone,
+    SPI::Error: Clone,
+    DC: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            spi: self.spi.clone(),
+            dc: self.dc.clone(),
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<SPI, DC> Copy for SpiInterface<SPI, DC>
+where
+    SPI: embedded_hal_1::spi::ErrorType + Copy,
+    SPI::Error: Copy,
+    DC: Copy,
+{
+}
+
+impl<SPI, DC> WriteOnlyDataCommand for SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.write(false, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.write(true, buf)
+    }
+}
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<'a, I2C> I2cInterface<i2c::RefCellDevice<'a, I2C>>
+where
+    I2C: I2c,
+{
+    /// Create a new I2C interface with the default address of 0x3C on a bus shared in a
+    /// [`RefCell`].
+    ///
+    /// Use [`new_custom_address`](Self::new_custom_address) with a
+    /// [`RefCellDevice`](i2c::RefCellDevice) for a different address.
+    pub fn new_shared(bus: &'a RefCell<I2C>) -> Self {
+        Self::new(i2c::RefCellDevice::new(bus))
+    }
+}
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<'a, I2C> I2cInterface<i2c::CriticalSectionDevice<'a, I2C>>
+where
+    I2C: I2c,
+{
+    /// Create a new I2C interface with the default address of 0x3C on a bus shared in a
+    /// critical section [`Mutex`].
+    pub fn new_critical_section(bus: &'a Mutex<RefCell<I2C>>) -> Self {
+        Self::new(i2c::CriticalSectionDevice::new(bus))
+    }
+}
+
+#[cfg(all(feature = "embedded-hal-bus", target_has_atomic = "8"))]
+impl<'a, I2C> I2cInterface<i2c::AtomicDevice<'a, I2C>>
+where
+    I2C: I2c,
+{
+    /// Create a new I2C interface with the default address of 0x3C on a bus shared in an
+    /// [`AtomicCell`].
+    ///
+    /// Transfers while the bus is in use by another device fail with
+    /// [`DisplayError::BusWriteError`].
+    pub fn new_atomic(bus: &'a AtomicCell<I2C>) -> Self {
+        Self::new(i2c::AtomicDevice::new(bus))
+    }
+}
+
+// The driver doesn't use delays within SPI transactions, so the devices are created without them
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<'a, SPI, CS, DC> SpiInterface<spi::RefCellDevice<'a, SPI, CS, spi::NoDelay>, DC>
+where
+    SPI: embedded_hal_1::spi::SpiBus,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    /// Create a new SPI interface on a bus shared in a [`RefCell`], with its own chip select
+    /// pin.
+    ///
+    /// Returns [`DisplayError::CSError`] if the chip select pin can't be set high.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::{PinStub, SpiStub};
+    /// # let (spi, cs, dc) = (SpiStub, PinStub, PinStub);
+    /// use core::cell::RefCell;
+    /// use ssd1306::{hal1::SpiInterface, prelude::*, Ssd1306};
+    ///
+    /// let bus = RefCell::new(spi);
+    ///
+    /// let interface = SpiInterface::new_shared(&bus, cs, dc).unwrap();
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn new_shared(bus: &'a RefCell<SPI>, cs: CS, dc: DC) -> Result<Self, DisplayError> {
+        let spi = spi::RefCellDevice::new_no_delay(bus, cs).map_err(|_| DisplayError::CSError)?;
+
+        Ok(Self::new(spi, dc))
+    }
+}
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<'a, SPI, CS, DC> SpiInterface<spi::CriticalSectionDevice<'a, SPI, CS, spi::NoDelay>, DC>
+where
+    SPI: embedded_hal_1::spi::SpiBus,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    /// Create a new SPI interface on a bus shared in a critical section [`Mutex`], with its own
+    /// chip select pin.
+    ///
+    /// Returns [`DisplayError::CSError`] if the chip select pin can't be set high.
+    pub fn new_critical_section(
+        bus: &'a Mutex<RefCell<SPI>>,
+        cs: CS,
+        dc: DC,
+    ) -> Result<Self, DisplayError> {
+        let spi =
+            spi::CriticalSectionDevice::new_no_delay(bus, cs).map_err(|_| DisplayError::CSError)?;
+
+        Ok(Self::new(spi, dc))
+    }
+}
+
+#[cfg(all(feature = "embedded-hal-bus", target_has_atomic = "8"))]
+impl<'a, SPI, CS, DC> SpiInterface<spi::AtomicDevice<'a, SPI, CS, spi::NoDelay>, DC>
+where
+    SPI: embedded_hal_1::spi::SpiBus,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    /// Create a new SPI interface on a bus shared in an [`AtomicCell`], with its own chip select
+    /// pin.
+    ///
+    /// Returns [`DisplayError::CSError`] if the chip select pin can't be set high. Transfers while
+    /// the bus is in use by another device fail with [`DisplayError::BusWriteError`].
+    pub fn new_atomic(bus: &'a AtomicCell<SPI>, cs: CS, dc: DC) -> Result<Self, DisplayError> {
+        let spi = spi::AtomicDevice::new_no_delay(bus, cs).map_err(|_| DisplayError::CSError)?;
+
+        Ok(Self::new(spi, dc))
+    }
+}
@@ -0,0 +1,616 @@
+//! Interfaces for the async I2C and SPI buses of `embedded-hal-async`.
+//!
+//! The driver itself is blocking, so these interfaces queue the transfers of the driver and send
+//! them when the queue is transmitted, which is awaited. [`Ssd1306::init_async`] and
+//! [`Ssd1306::flush_async`] initialise and flush the display this way, and
+//! [`Ssd1306::transmit`] sends the transfers of any other method, such as changing a setting or
+//! writing text in [`TerminalMode`](crate::mode::TerminalMode). They are enabled by the `async`
+//! feature, which needs Rust 1.75 or later.
+//!
+//! Consecutive command or data bytes are sent in a single transaction. The queue holds
+//! [`QUEUE_SIZE`] bytes by default, enough for a whole frame of a 128x64 display and the commands
+//! around it, and can be resized with `with_queue_size`. Transfers which don't fit in the queue
+//! fail with [`DisplayError::OutOfBoundsError`].
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::I2cStub;
+//! # let i2c = I2cStub;
+//! # embassy_futures::block_on(async {
+//! use ssd1306::{hal_async::I2cInterface, prelude::*, Ssd1306};
+//!
+//! let interface = I2cInterface::new(i2c);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init_async().await.unwrap();
+//!
+//! display.set_pixel(10, 20, true);
+//! display.flush_async().await.unwrap();
+//!
+//! display.set_brightness(Brightness::DIMMEST).unwrap();
+//! display.transmit().await.unwrap();
+//! # });
+//! ```
+//!
+//! The display can be reset with an async delay by [`Ssd1306::reset_async`], or reset and
+//! initialised by [`Ssd1306::init_with_reset_async`].
+//!
+//! Methods which wait between their transfers, such as
+//! [`soft_reset`](Ssd1306::soft_reset), don't wait for the queued transfers to be sent, so they
+//! should be used with the blocking interfaces of the [`hal1`](crate::hal1) module instead.
+
+use core::{convert::Infallible, fmt};
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_hal_1::digital::OutputPin;
+use embedded_hal_async::{delay::DelayNs, i2c::I2c, spi::SpiDevice};
+
+use crate::{
+    error::{DriverError, Operation, TakeBusError},
+    mode::{BufferedGraphicsMode, DisplayConfig},
+    reset::ResetConfig,
+    size::DisplaySize,
+    Error, Ssd1306,
+};
+
+/// The default size of the queue of the interfaces in bytes, enough for a whole frame of a 128x64
+/// display and the commands to initialise the display and select the area to draw.
+pub const QUEUE_SIZE: usize = 1152;
+
+/// Size of the header of every run of command or data bytes in the queue
+const HEADER: usize = 3;
+
+/// An interface which queues the transfers of the driver, to send them asynchronously.
+#[allow(async_fn_in_trait)]
+pub trait QueuedInterface {
+    /// Send the queued transfers.
+    ///
+    /// The queue is emptied, also if a transfer fails.
+    async fn transmit(&mut self) -> Result<(), DisplayError>;
+}
+
+/// Transfers waiting to be sent, as runs of command or data bytes which each start with a header
+/// of their kind and length
+#[derive(Debug, Clone, Copy)]
+struct Queue<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+    /// Start of the last run, which bytes of the same kind are appended to
+    last: Option<usize>,
+}
+
+impl<const N: usize> Queue<N> {
+    fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+            last: None,
+        }
+    }
+
+    fn run_len(&self, start: usize) -> usize {
+        u16::from_le_bytes([self.bytes[start + 1], self.bytes[start + 2]]).into()
+    }
+
+    /// Queue command bytes, or data bytes if `data` is set.
+    fn push(&mut self, data: bool, format: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match format {
+            DataFormat::U8(bytes) => bytes,
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        // The display doesn't need the bytes to be split into the transfers of the driver
+        let last = self.last.filter(|&start| {
+            self.bytes[start] == data as u8
+                && self.run_len(start) + bytes.len() <= usize::from(u16::MAX)
+        });
+        let header = if last.is_some() { 0 } else { HEADER };
+        if self.len + header + bytes.len() > N {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        let start = last.unwrap_or_else(|| {
+            let start = self.len;
+            self.bytes[start] = data as u8;
+            self.len += HEADER;
+            self.last = Some(start);
+            start
+        });
+
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+
+        let run_len = (self.len - start - HEADER) as u16;
+        self.bytes[start + 1..start + HEADER].copy_from_slice(&run_len.to_le_bytes());
+
+        Ok(())
+    }
+
+    /// Get the queued runs, with whether they are data bytes.
+    fn runs(&self) -> impl Iterator<Item = (bool, &[u8])> + '_ {
+        let mut start = 0;
+
+        core::iter::from_fn(move || {
+            if start >= self.len {
+                return None;
+            }
+
+            let end = start + HEADER + self.run_len(start);
+            let run = (self.bytes[start] != 0, &self.bytes[start + HEADER..end]);
+            start = end;
+
+            Some(run)
+        })
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+        self.last = None;
+    }
+}
+
+/// An interface for an async I2C bus of `embedded-hal-async`, holding a queue of `N` bytes.
+///
+/// Errors of the I2C bus are returned as [`DisplayError::BusWriteError`] by
+/// [`transmit`](QueuedInterface::transmit), but the original error of the last failed transfer
+/// is kept, and can be taken with [`take_bus_error`](TakeBusError::take_bus_error).
+///
+/// See the [module level documentation](crate::hal_async) for an example.
+pub struct I2cInterface<I2C, const N: usize = QUEUE_SIZE>
+where
+    I2C: embedded_hal_1::i2c::ErrorType,
+{
+    i2c: I2C,
+    address: u8,
+    queue: Queue<N>,
+    bus_error: Option<I2C::Error>,
+}
+
+impl<I2C> I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    /// Create a new I2C interface with the default address of 0x3C.
+    pub fn new(i2c: I2C) -> Self {
+        Self::new_custom_address(i2c, 0x3C)
+    }
+
+    /// Create a new I2C interface with the alternate address 0x3D as specified in the datasheet.
+    pub fn new_alternate_address(i2c: I2C) -> Self {
+        Self::new_custom_address(i2c, 0x3D)
+    }
+
+    /// Create a new I2C interface with a custom address.
+    pub fn new_custom_address(i2c: I2C, address: u8) -> Self {
+        Self {
+            i2c,
+            address,
+            queue: Queue::new(),
+            bus_error: None,
+        }
+    }
+}
+
+impl<I2C, const N: usize> I2cInterface<I2C, N>
+where
+    I2C: I2c,
+{
+    /// Resize the queue to `M` bytes, dropping any queued transfers.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::I2cStub;
+    /// # let i2c = I2cStub;
+    /// use ssd1306::hal_async::I2cInterface;
+    ///
+    /// // Enough for a whole frame of a 128x32 display
+    /// let interface = I2cInterface::new(i2c).with_queue_size::<640>();
+    /// ```
+    pub fn with_queue_size<const M: usize>(self) -> I2cInterface<I2C, M> {
+        I2cInterface {
+            i2c: self.i2c,
+            address: self.address,
+            queue: Queue::new(),
+            bus_error: self.bus_error,
+        }
+    }
+
+    /// Release the contained I2C bus.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C, const N: usize> TakeBusError for I2cInterface<I2C, N>
+where
+    I2C: embedded_hal_1::i2c::ErrorType,
+{
+    type BusError = I2C::Error;
+
+    fn take_bus_error(&mut self) -> Option<I2C::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<I2C, const N: usize> fmt::Debug for I2cInterface<I2C, N>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("I2cInterface")
+            .field("i2c", &self.i2c)
+            .field("address", &self.address)
+            .field("queue", &self.queue)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<I2C, const N: usize> Clone for I2cInterface<I2C, N>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + Clone,
+    I2C::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            i2c: self.i2c.clone(),
+            address: self.address,
+            queue: self.queue,
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<I2C, const N: usize> Copy for I2cInterface<I2C, N>
+where
+    I2C: embedded_hal_1::i2c::ErrorType + Copy,
+    I2C::Error: Copy,
+{
+}
+
+impl<I2C, const N: usize> WriteOnlyDataCommand for I2cInterface<I2C, N>
+where
+    I2C: I2c,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.queue.push(false, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.queue.push(true, buf)
+    }
+}
+
+impl<I2C, const N: usize> QueuedInterface for I2cInterface<I2C, N>
+where
+    I2C: I2c,
+{
+    async fn transmit(&mut self) -> Result<(), DisplayError> {
+        let mut result = Ok(());
+
+        for (data, bytes) in self.queue.runs() {
+            // The bytes are preceded by a control byte with D/C# set for data
+            let control = if data { 0x40 } else { 0x00 };
+
+            let write = self
+                .i2c
+                .transaction(
+                    self.address,
+                    &mut [
+                        embedded_hal_1::i2c::Operation::Write(&[control]),
+                        embedded_hal_1::i2c::Operation::Write(bytes),
+                    ],
+                )
+                .await;
+            if let Err(error) = write {
+                self.bus_error = Some(error);
+                result = Err(DisplayError::BusWriteError);
+                break;
+            }
+        }
+
+        self.queue.clear();
+        result
+    }
+}
+
+/// An interface for an async SPI device of `embedded-hal-async` and a data/command pin, holding
+/// a queue of `N` bytes.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::{PinStub, SpiStub};
+/// # let (spi, dc) = (SpiStub, PinStub);
+/// # embassy_futures::block_on(async {
+/// use ssd1306::{hal_async::SpiInterface, prelude::*, Ssd1306};
+///
+/// let interface = SpiInterface::new(spi, dc);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init_async().await.unwrap();
+/// # });
+/// ```
+///
+/// Errors of the SPI device are returned as [`DisplayError::BusWriteError`] by
+/// [`transmit`](QueuedInterface::transmit), but the original error of the last failed transfer
+/// is kept, and can be taken with [`take_bus_error`](TakeBusError::take_bus_error).
+pub struct SpiInterface<SPI, DC, const N: usize = QUEUE_SIZE>
+where
+    SPI: embedded_hal_1::spi::ErrorType,
+{
+    spi: SPI,
+    dc: DC,
+    queue: Queue<N>,
+    bus_error: Option<SPI::Error>,
+}
+
+impl<SPI, DC> SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    /// Create a new SPI interface.
+    pub fn new(spi: SPI, dc: DC) -> Self {
+        Self {
+            spi,
+            dc,
+            queue: Queue::new(),
+            bus_error: None,
+        }
+    }
+}
+
+impl<SPI, DC, const N: usize> SpiInterface<SPI, DC, N>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    /// Resize the queue to `M` bytes, dropping any queued transfers.
+    pub fn with_queue_size<const M: usize>(self) -> SpiInterface<SPI, DC, M> {
+        SpiInterface {
+            spi: self.spi,
+            dc: self.dc,
+            queue: Queue::new(),
+            bus_error: self.bus_error,
+        }
+    }
+
+    /// Release the contained SPI device and data/command pin.
+    pub fn release(self) -> (SPI, DC) {
+        (self.spi, self.dc)
+    }
+}
+
+impl<SPI, DC, const N: usize> TakeBusError for SpiInterface<SPI, DC, N>
+where
+    SPI: embedded_hal_1::spi::ErrorType,
+{
+    type BusError = SPI::Error;
+
+    fn take_bus_error(&mut self) -> Option<SPI::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<SPI, DC, const N: usize> fmt::Debug for SpiInterface<SPI, DC, N>
+where
+    SPI: embedded_hal_1::spi::ErrorType + fmt::Debug,
+    DC: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpiInterface")
+            .field("spi", &self.spi)
+            .field("dc", &self.dc)
+            .field("queue", &self.queue)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<SPI, DC, const N: usize> Clone for SpiInterface<SPI, DC, N>
+where
+    SPI: embedded_hal_1::spi::ErrorType + Clone,
+    SPI::Error: Clone,
+    DC: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            spi: self.spi.clone(),
+            dc: self.dc.clone(),
+            queue: self.queue,
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<SPI, DC, const N: usize> Copy for SpiInterface<SPI, DC, N>
+where
+    SPI: embedded_hal_1::spi::ErrorType + Copy,
+    SPI::Error: Copy,
+    DC: Copy,
+{
+}
+
+impl<SPI, DC, const N: usize> WriteOnlyDataCommand for SpiInterface<SPI, DC, N>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.queue.push(false, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.queue.push(true, buf)
+    }
+}
+
+impl<SPI, DC, const N: usize> QueuedInterface for SpiInterface<SPI, DC, N>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    async fn transmit(&mut self) -> Result<(), DisplayError> {
+        let mut result = Ok(());
+
+        for (data, bytes) in self.queue.runs() {
+            let dc = if data {
+                self.dc.set_high()
+            } else {
+                self.dc.set_low()
+            };
+            if dc.is_err() {
+                result = Err(DisplayError::DCError);
+                break;
+            }
+
+            if let Err(error) = self.spi.write(bytes).await {
+                self.bus_error = Some(error);
+                result = Err(DisplayError::BusWriteError);
+                break;
+            }
+        }
+
+        self.queue.clear();
+        result
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: QueuedInterface,
+{
+    /// Send the transfers queued by the interface since they were last sent.
+    pub async fn transmit(&mut self) -> Result<(), DriverError> {
+        self.transmit_operation(Operation::Other).await
+    }
+
+    /// Send the queued transfers, adding `operation` to the error of a failed transfer
+    pub(crate) async fn transmit_operation(
+        &mut self,
+        operation: Operation,
+    ) -> Result<(), DriverError> {
+        self.interface
+            .iface
+            .transmit()
+            .await
+            .map_err(|error| DriverError::new(error, operation, None))
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE> {
+    /// Reset the display, waiting with an async delay.
+    ///
+    /// This uses the default [`ResetConfig`], for an active low reset pin.
+    pub async fn reset_async<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<Infallible, RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        self.reset_with_config_async(rst, delay, ResetConfig::default())
+            .await
+    }
+
+    /// Reset the display with a custom reset pin polarity and pulse timing, waiting with an
+    /// async delay.
+    pub async fn reset_with_config_async<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+        config: ResetConfig,
+    ) -> Result<(), Error<Infallible, RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        let mut set_active = |active: bool| {
+            let result = if active == config.active_high {
+                rst.set_high()
+            } else {
+                rst.set_low()
+            };
+            result.map_err(Error::Pin)
+        };
+
+        set_active(false)?;
+        delay.delay_ms(config.idle_ms.into()).await;
+        set_active(true)?;
+        delay.delay_ms(config.pulse_ms.into()).await;
+        set_active(false)?;
+        delay.delay_ms(config.settle_ms.into()).await;
+
+        Ok(())
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand + QueuedInterface,
+    SIZE: DisplaySize,
+    Self: DisplayConfig,
+    <Self as DisplayConfig>::Error: From<DriverError>,
+{
+    /// Initialise the display in the current mode with [`DisplayConfig::init`], and send the
+    /// queued init sequence.
+    pub async fn init_async(&mut self) -> Result<(), <Self as DisplayConfig>::Error> {
+        self.init()?;
+        self.transmit_operation(Operation::Init).await?;
+
+        Ok(())
+    }
+
+    /// Reset the display with its reset pin and then initialise it in the current mode, waiting
+    /// with an async delay.
+    ///
+    /// This is the same as awaiting [`reset_async`](Ssd1306::reset_async) followed by
+    /// [`init_async`](Ssd1306::init_async).
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::{DelayStub, I2cStub, PinStub};
+    /// # let (i2c, mut rst, mut delay) = (I2cStub, PinStub, DelayStub);
+    /// # embassy_futures::block_on(async {
+    /// use ssd1306::{hal_async::I2cInterface, prelude::*, Ssd1306};
+    ///
+    /// let interface = I2cInterface::new(i2c);
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    ///
+    /// display
+    ///     .init_with_reset_async(&mut rst, &mut delay)
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn init_with_reset_async<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<<Self as DisplayConfig>::Error, RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        self.reset_async(rst, delay)
+            .await
+            .map_err(|err| match err {
+                Error::Pin(err) => Error::Pin(err),
+                Error::Comm(never) => match never {},
+            })?;
+
+        self.init_async().await.map_err(Error::Comm)
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand + QueuedInterface,
+    SIZE: DisplaySize,
+{
+    /// Write out the parts of the buffer which have changed since the last flush, like
+    /// [`flush`](Ssd1306::flush), and send them.
+    pub async fn flush_async(&mut self) -> Result<(), DriverError> {
+        self.flush()?;
+        self.transmit_operation(Operation::Flush).await
+    }
+}
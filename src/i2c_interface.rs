@@ -1,6 +1,14 @@
 //! I2C interface factory
 
+use core::{fmt, iter};
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
 use display_interface_i2c::I2CInterface;
+use embedded_hal::blocking::i2c;
+
+use crate::{
+    error::TakeBusError,
+    status::{AcknowledgeError, DetectNoAcknowledge, ReadStatus},
+};
 
 /// Helper struct to create preconfigured I2C interfaces for the display.
 #[derive(Debug, Copy, Clone)]
@@ -12,7 +20,7 @@ impl I2CDisplayInterface {
     // pub fn with_i2c<I>(i2c: I) -> I2CInterface<I> // alternative, but breaking change
     pub fn new<I>(i2c: I) -> I2CInterface<I>
     where
-        I: embedded_hal::blocking::i2c::Write,
+        I: i2c::Write,
     {
         Self::new_custom_address(i2c, 0x3C)
     }
@@ -20,7 +28,7 @@ impl I2CDisplayInterface {
     /// Create a new I2C interface with the alternate address 0x3D as specified in the datasheet.
     pub fn new_alternate_address<I>(i2c: I) -> I2CInterface<I>
     where
-        I: embedded_hal::blocking::i2c::Write,
+        I: i2c::Write,
     {
         Self::new_custom_address(i2c, 0x3D)
     }
@@ -28,8 +36,348 @@ impl I2CDisplayInterface {
     /// Create a new I2C interface with a custom address.
     pub fn new_custom_address<I>(i2c: I, address: u8) -> I2CInterface<I>
     where
-        I: embedded_hal::blocking::i2c::Write,
+        I: i2c::Write,
     {
         I2CInterface::new(i2c, address, 0x40)
     }
+
+    /// Create a new I2C interface with the default address of 0x3C, which sends each transfer in a
+    /// single I2C write.
+    ///
+    /// See [`ContinuousI2CInterface`] for details.
+    pub fn new_continuous<I>(i2c: I) -> ContinuousI2CInterface<I>
+    where
+        I: i2c::WriteIter,
+    {
+        Self::new_continuous_custom_address(i2c, 0x3C)
+    }
+
+    /// Create a new I2C interface with a custom address, which sends each transfer in a single
+    /// I2C write.
+    ///
+    /// See [`ContinuousI2CInterface`] for details.
+    pub fn new_continuous_custom_address<I>(i2c: I, address: u8) -> ContinuousI2CInterface<I>
+    where
+        I: i2c::WriteIter,
+    {
+        ContinuousI2CInterface {
+            i2c,
+            address,
+            bus_error: None,
+        }
+    }
+
+    /// Create a new I2C interface with the default address of 0x3C, which can also read the
+    /// status of the display with [`Ssd1306::read_status`](crate::Ssd1306::read_status).
+    pub fn new_readable<I>(i2c: I) -> ReadableI2CInterface<I>
+    where
+        I: i2c::Write + i2c::Read<Error = <I as i2c::Write>::Error>,
+    {
+        Self::new_readable_custom_address(i2c, 0x3C)
+    }
+
+    /// Create a new I2C interface with a custom address, which can also read the status of the
+    /// display with [`Ssd1306::read_status`](crate::Ssd1306::read_status).
+    pub fn new_readable_custom_address<I>(i2c: I, address: u8) -> ReadableI2CInterface<I>
+    where
+        I: i2c::Write + i2c::Read<Error = <I as i2c::Write>::Error>,
+    {
+        ReadableI2CInterface {
+            i2c,
+            address,
+            bus_error: None,
+        }
+    }
+}
+
+/// I2C interface which can also read the status of the display, created with
+/// [`I2CDisplayInterface::new_readable`].
+///
+/// Errors of the I2C bus are returned as [`DisplayError::BusWriteError`] by the driver, but the
+/// original error of the last failed transfer is kept, and can be taken with
+/// [`take_bus_error`](TakeBusError::take_bus_error) to handle specific bus failures.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::I2cStub;
+/// # let i2c = I2cStub;
+/// use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+///
+/// let interface = I2CDisplayInterface::new_readable(i2c);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+///
+/// if display.init().is_err() {
+///     match display.interface_mut().take_bus_error() {
+///         // Handle the error of the HAL, for example an arbitration loss
+///         Some(error) => {}
+///         None => {}
+///     }
+/// }
+/// ```
+pub struct ReadableI2CInterface<I>
+where
+    I: i2c::Write,
+{
+    i2c: I,
+    address: u8,
+    bus_error: Option<I::Error>,
+}
+
+impl<I> ReadableI2CInterface<I>
+where
+    I: i2c::Write,
+{
+    /// Release the contained I2C bus.
+    pub fn release(self) -> I {
+        self.i2c
+    }
+
+    /// Keep the error of a transfer, and flatten it into a [`DisplayError`].
+    fn keep_error<T>(&mut self, result: Result<T, I::Error>) -> Result<T, DisplayError> {
+        result.map_err(|error| {
+            self.bus_error = Some(error);
+            DisplayError::BusWriteError
+        })
+    }
+}
+
+impl<I> TakeBusError for ReadableI2CInterface<I>
+where
+    I: i2c::Write,
+{
+    type BusError = I::Error;
+
+    fn take_bus_error(&mut self) -> Option<I::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<I> DetectNoAcknowledge for ReadableI2CInterface<I>
+where
+    I: i2c::Write,
+    I::Error: AcknowledgeError,
+{
+    fn no_acknowledge(&self) -> bool {
+        self.bus_error
+            .as_ref()
+            .map_or(false, AcknowledgeError::is_no_acknowledge)
+    }
+}
+
+impl<I> fmt::Debug for ReadableI2CInterface<I>
+where
+    I: i2c::Write + fmt::Debug,
+    I::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadableI2CInterface")
+            .field("i2c", &self.i2c)
+            .field("address", &self.address)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<I> Clone for ReadableI2CInterface<I>
+where
+    I: i2c::Write + Clone,
+    I::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            i2c: self.i2c.clone(),
+            address: self.address,
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<I> Copy for ReadableI2CInterface<I>
+where
+    I: i2c::Write + Copy,
+    I::Error: Copy,
+{
+}
+
+impl<I> WriteOnlyDataCommand for ReadableI2CInterface<I>
+where
+    I: i2c::Write,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match cmd {
+            DataFormat::U8(bytes) if bytes.len() <= 7 => bytes,
+            DataFormat::U8(_) => return Err(DisplayError::InvalidFormatError),
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        // Command bytes are preceded by a control byte with D/C# cleared
+        let mut buffer = [0; 8];
+        buffer[1..=bytes.len()].copy_from_slice(bytes);
+
+        let result = self.i2c.write(self.address, &buffer[..=bytes.len()]);
+        self.keep_error(result)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match buf {
+            DataFormat::U8(bytes) => bytes,
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        // Data bytes are preceded by a control byte with D/C# set
+        let mut buffer = [0x40; 17];
+        bytes.chunks(16).try_for_each(|chunk| {
+            buffer[1..=chunk.len()].copy_from_slice(chunk);
+
+            let result = self.i2c.write(self.address, &buffer[..=chunk.len()]);
+            self.keep_error(result)
+        })
+    }
+}
+
+impl<I> ReadStatus for ReadableI2CInterface<I>
+where
+    I: i2c::Write + i2c::Read<Error = <I as i2c::Write>::Error>,
+{
+    fn read_status(&mut self) -> Result<u8, DisplayError> {
+        let mut status = [0];
+
+        let result = self.i2c.read(self.address, &mut status);
+        self.keep_error(result)?;
+
+        Ok(status[0])
+    }
+}
+
+/// I2C interface which sends each transfer in a single I2C write, created with
+/// [`I2CDisplayInterface::new_continuous`].
+///
+/// The [`I2CInterface`] copies data into writes of 16 bytes, each with its own control byte,
+/// because the `Write` trait of `embedded-hal` needs all bytes of a write in one slice. This
+/// interface uses the `WriteIter` trait instead, so that the whole data of a flush is sent after
+/// a single control byte, which takes less time on the bus and gives higher frame rates.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::I2cStub;
+/// # let i2c = I2cStub;
+/// use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+///
+/// let interface = I2CDisplayInterface::new_continuous(i2c);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// display.flush().unwrap();
+/// ```
+///
+/// Errors of the I2C bus are returned as [`DisplayError::BusWriteError`] by the driver, but the
+/// original error of the last failed transfer is kept, and can be taken with
+/// [`take_bus_error`](TakeBusError::take_bus_error).
+pub struct ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter,
+{
+    i2c: I,
+    address: u8,
+    bus_error: Option<I::Error>,
+}
+
+impl<I> ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter,
+{
+    /// Release the contained I2C bus.
+    pub fn release(self) -> I {
+        self.i2c
+    }
+
+    /// Write the bytes of a transfer after the `control` byte.
+    fn write(&mut self, control: u8, format: DataFormat<'_>) -> Result<(), DisplayError> {
+        let result = match format {
+            DataFormat::U8(bytes) => self.i2c.write(
+                self.address,
+                iter::once(control).chain(bytes.iter().copied()),
+            ),
+            DataFormat::U8Iter(bytes) => self
+                .i2c
+                .write(self.address, iter::once(control).chain(bytes)),
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        result.map_err(|error| {
+            self.bus_error = Some(error);
+            DisplayError::BusWriteError
+        })
+    }
+}
+
+impl<I> TakeBusError for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter,
+{
+    type BusError = I::Error;
+
+    fn take_bus_error(&mut self) -> Option<I::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<I> DetectNoAcknowledge for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter,
+    I::Error: AcknowledgeError,
+{
+    fn no_acknowledge(&self) -> bool {
+        self.bus_error
+            .as_ref()
+            .map_or(false, AcknowledgeError::is_no_acknowledge)
+    }
+}
+
+impl<I> fmt::Debug for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter + fmt::Debug,
+    I::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContinuousI2CInterface")
+            .field("i2c", &self.i2c)
+            .field("address", &self.address)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<I> Clone for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter + Clone,
+    I::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            i2c: self.i2c.clone(),
+            address: self.address,
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<I> Copy for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter + Copy,
+    I::Error: Copy,
+{
+}
+
+impl<I> WriteOnlyDataCommand for ContinuousI2CInterface<I>
+where
+    I: i2c::WriteIter,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        // A control byte with Co and D/C# cleared makes all following bytes commands
+        self.write(0x00, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        // A control byte with Co cleared and D/C# set makes all following bytes data
+        self.write(0x40, buf)
+    }
 }
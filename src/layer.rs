@@ -0,0 +1,239 @@
+//! Layers composited onto the buffer of a display.
+//!
+//! A [`Layer`] is a buffer of the same size as the buffer of a display in
+//! [buffered graphics mode](crate::mode::BufferedGraphicsMode). Layers are combined with the
+//! display's buffer by [`flush_layers`](crate::Ssd1306::flush_layers) using a [`RasterOp`]
+//! while sending it, without changing the display's buffer. A popup can be drawn to a layer and
+//! hidden again without redrawing the scene below it.
+//!
+//! Each layer tracks the area which was changed in it, and the area it covers, so only the
+//! changed parts of the display are sent when a layer is drawn to, shown or hidden.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{PrimitiveStyle, Rectangle},
+//! };
+//! use ssd1306::{prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Draw the scene to the display's buffer
+//! display.set_pixel(10, 20, true);
+//!
+//! // Draw an inverted box on top of it
+//! let mut popup = display.new_layer(RasterOp::Xor);
+//! Rectangle::new(Point::new(32, 16), Size::new(64, 32))
+//!     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+//!     .draw(&mut popup)
+//!     .unwrap();
+//! display.flush_layers(&mut [&mut popup]).unwrap();
+//!
+//! // Only the area of the popup is sent again
+//! popup.set_visible(false);
+//! display.flush_layers(&mut [&mut popup]).unwrap();
+//! ```
+
+use crate::{
+    mode::{pixel_index, BufferedGraphicsMode},
+    raster_op::RasterOp,
+    rotation::DisplayRotation,
+    size::{DisplaySize, NewZeroed},
+    Ssd1306,
+};
+use display_interface::WriteOnlyDataCommand;
+
+/// An area of a layer, in display coordinates
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Area {
+    pub(crate) min_x: u8,
+    pub(crate) max_x: u8,
+    pub(crate) min_y: u8,
+    pub(crate) max_y: u8,
+}
+
+impl Area {
+    const EMPTY: Area = Area {
+        min_x: 255,
+        max_x: 0,
+        min_y: 255,
+        max_y: 0,
+    };
+
+    fn include(&mut self, x: u8, y: u8) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    fn union(&mut self, other: Area) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.max_x = self.max_x.max(other.max_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+}
+
+/// A buffer which is composited onto the buffer of a display while flushing.
+///
+/// Created by [`Ssd1306::new_layer`]. See the [module documentation](crate::layer) for an
+/// example.
+#[derive(Clone, Debug)]
+pub struct Layer<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    buffer: SIZE::Buffer,
+    rotation: DisplayRotation,
+    width: u8,
+    height: u8,
+    op: RasterOp,
+    visible: bool,
+    /// Area changed since the last flush
+    dirty: Area,
+    /// Area drawn to since the layer was cleared
+    used: Area,
+}
+
+impl<SIZE> Layer<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Get the raster operation the layer is combined with.
+    pub fn op(&self) -> RasterOp {
+        self.op
+    }
+
+    /// Change the raster operation the layer is combined with.
+    pub fn set_op(&mut self, op: RasterOp) {
+        if op != self.op {
+            self.op = op;
+            self.dirty.union(self.used);
+        }
+    }
+
+    /// Get whether the layer is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the layer. The area the layer was drawn to is sent by the next flush.
+    pub fn set_visible(&mut self, visible: bool) {
+        if visible != self.visible {
+            self.visible = visible;
+            self.dirty.union(self.used);
+        }
+    }
+
+    /// Turn off all pixels of the layer. The area the layer was drawn to is sent by the next
+    /// flush.
+    pub fn clear(&mut self) {
+        self.buffer.as_mut().fill(0);
+        self.dirty.union(self.used);
+        self.used = Area::EMPTY;
+    }
+
+    /// Turn a pixel of the layer on or off. If the X and Y coordinates are out of the bounds of
+    /// the display, this method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        if x >= self.width.into() || y >= self.height.into() {
+            return;
+        }
+
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+
+        if let Some(byte) = self.buffer.as_mut().get_mut(idx) {
+            self.dirty.include(x as u8, y as u8);
+            self.used.include(x as u8, y as u8);
+
+            *byte = *byte & !(1 << bit) | ((value as u8) << bit);
+        }
+    }
+
+    /// Get the area changed since the last flush, and start tracking changes again
+    pub(crate) fn take_dirty(&mut self) -> Area {
+        core::mem::replace(&mut self.dirty, Area::EMPTY)
+    }
+
+    /// Combine the bytes of the layer at `range` with `data`, if the layer is visible
+    pub(crate) fn composite(&mut self, range: core::ops::Range<usize>, data: &mut [u8]) {
+        if self.visible {
+            for (dst, src) in data.iter_mut().zip(&self.buffer.as_mut()[range]) {
+                *dst = self.op.apply(*dst, *src);
+            }
+        }
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Create an empty, visible [`Layer`] for this display, which is combined with the display's
+    /// buffer using `op`.
+    ///
+    /// The layer uses the rotation and dimensions of the display when it was created, so layers
+    /// should be created again after changing the rotation.
+    pub fn new_layer(&self, op: RasterOp) -> Layer<SIZE> {
+        let (width, height) = self.dimensions();
+
+        Layer {
+            buffer: NewZeroed::new_zeroed(),
+            rotation: self.rotation,
+            width,
+            height,
+            op,
+            visible: true,
+            dirty: Area::EMPTY,
+            used: Area::EMPTY,
+        }
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+impl<SIZE> DrawTarget for Layer<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| pos.x >= 0 && pos.y >= 0)
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on())
+            });
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<SIZE> OriginDimensions for Layer<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        Size::new(self.width.into(), self.height.into())
+    }
+}
@@ -12,6 +12,26 @@
 //! - [`TerminalMode`] - A bufferless mode supporting drawing text to the display, as well as
 //!   setting cursor positions like a simple terminal.
 //!
+//! # Interfaces
+//!
+//! The I2C and SPI interfaces from the `display-interface-i2c` and `display-interface-spi` crates
+//! are re-exported as [`I2CInterface`], [`SPIInterface`] and [`SPIInterfaceNoCS`], and in the
+//! [`prelude`]. Use these rather than adding a dependency on the interface crates, which fails
+//! to compile with errors such as "the trait `DrawTarget` is not implemented" when the versions
+//! don't match the ones used by this crate. The [`display_interface`] crate is re-exported too,
+//! for implementing custom interfaces.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::{PinStub, SpiStub};
+//! # let (spi, dc) = (SpiStub, PinStub);
+//! use ssd1306::{prelude::*, SPIInterfaceNoCS, Ssd1306};
+//!
+//! let interface = SPIInterfaceNoCS::new(spi, dc);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//! ```
+//!
 //! # Examples
 //!
 //! Examples can be found in [the examples/
@@ -108,40 +128,121 @@
 #![deny(unused_qualifications)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod animation;
+pub mod auto_dim;
+#[cfg(feature = "bitbang")]
+pub mod bitbang;
 mod brightness;
+pub mod burn_in;
+mod clock;
 pub mod command;
 mod error;
+#[cfg(feature = "embedded-hal-1")]
+pub mod hal1;
+#[cfg(feature = "async")]
+pub mod hal_async;
 mod i2c_interface;
+pub mod layer;
+#[cfg(feature = "logger")]
+pub mod logger;
+#[cfg(feature = "test-util")]
+pub mod mock;
 pub mod mode;
+#[cfg(feature = "panic-screen")]
+pub mod panic_screen;
+pub mod pbm;
+#[cfg(feature = "std")]
+mod png;
 pub mod prelude;
+mod raster_op;
+pub mod reset;
+pub mod retry;
+pub mod rle;
 pub mod rotation;
+#[cfg(feature = "graphics")]
+pub mod scaled;
+#[cfg(feature = "simulator")]
+pub mod simulator;
 pub mod size;
+mod state;
+mod stats;
+pub mod status;
+#[cfg(feature = "graphics")]
+pub mod tee;
 #[doc(hidden)]
 pub mod test_helpers;
+mod three_wire_spi;
+#[cfg(feature = "graphics")]
+pub mod threshold;
+#[cfg(feature = "graphics")]
+pub mod tiled;
+mod trace;
+pub mod transition;
+#[cfg(feature = "graphics")]
+pub mod viewport;
 
 use core::convert::Infallible;
 
-pub use crate::i2c_interface::I2CDisplayInterface;
+pub use crate::error::{DriverError, Operation, TakeBusError, Transfer};
+// The interfaces are re-exported so that applications always use the versions this crate is
+// built against
+pub use display_interface;
+pub use display_interface_i2c::{self, I2CInterface};
+pub use display_interface_spi::{self, SPIInterface, SPIInterfaceNoCS};
+
+pub use crate::i2c_interface::{ContinuousI2CInterface, I2CDisplayInterface, ReadableI2CInterface};
 use crate::mode::BasicMode;
+pub use crate::state::DisplayState;
+#[cfg(feature = "stats")]
+pub use crate::stats::Stats;
+pub use crate::three_wire_spi::ThreeWireSPIInterface;
 use brightness::Brightness;
-use command::{AddrMode, Command, VcomhLevel};
+use clock::DisplayClock;
+use command::{
+    AddrMode, Command, CommandBatch, FadeMode, HScrollDir, NFrames, Page, VHScrollDir, VcomhLevel,
+};
 use display_interface::{DataFormat::U8, DisplayError, WriteOnlyDataCommand};
 use embedded_hal::{blocking::delay::DelayMs, digital::v2::OutputPin};
 use error::Error;
-use mode::{BufferedGraphicsMode, TerminalMode};
+use mode::{
+    BufferedGraphicsMode, DisplayConfig, GrayscaleMode, PixelDoubledMode, TerminalDisplaySize,
+    TerminalMode,
+};
+use reset::ResetConfig;
 use rotation::DisplayRotation;
 use size::DisplaySize;
+use stats::Counting;
 
 /// SSD1306 driver.
 ///
 /// Note that some methods are only available when the display is configured in a certain [`mode`].
 #[derive(Copy, Clone, Debug)]
 pub struct Ssd1306<DI, SIZE, MODE> {
-    interface: DI,
+    interface: Counting<DI>,
     mode: MODE,
     size: SIZE,
     addr_mode: AddrMode,
     rotation: DisplayRotation,
+    zoom_in: bool,
+    external_vcc: bool,
+    precharge: Option<(u8, u8)>,
+    vcomh: VcomhLevel,
+    iref: Option<(bool, bool)>,
+    com_pins: Option<(bool, bool)>,
+    flip_segments: bool,
+    flip_com_scan: bool,
+    flip_x: bool,
+    flip_y: bool,
+    offset_x: u8,
+    offset_y: u8,
+    clock: DisplayClock,
+    init_commands: &'static [Command],
+    brightness: Brightness,
+    invert: bool,
+    display_on: bool,
 }
 
 impl<DI, SIZE> Ssd1306<DI, SIZE, BasicMode>
@@ -154,11 +255,28 @@ where
     /// Use the `into_*_mode` methods to enable more functionality.
     pub fn new(interface: DI, size: SIZE, rotation: DisplayRotation) -> Self {
         Self {
-            interface,
+            interface: Counting::new(interface),
             size,
             addr_mode: AddrMode::Page,
             mode: BasicMode,
             rotation,
+            zoom_in: false,
+            external_vcc: false,
+            precharge: None,
+            vcomh: VcomhLevel::Auto,
+            iref: None,
+            com_pins: None,
+            flip_segments: false,
+            flip_com_scan: false,
+            flip_x: false,
+            flip_y: false,
+            offset_x: SIZE::OFFSETX,
+            offset_y: SIZE::OFFSETY,
+            clock: DisplayClock::default(),
+            init_commands: &[],
+            brightness: Brightness::default(),
+            invert: false,
+            display_on: false,
         }
     }
 }
@@ -176,6 +294,23 @@ where
             interface: self.interface,
             size: self.size,
             rotation: self.rotation,
+            zoom_in: self.zoom_in,
+            external_vcc: self.external_vcc,
+            precharge: self.precharge,
+            vcomh: self.vcomh,
+            iref: self.iref,
+            com_pins: self.com_pins,
+            flip_segments: self.flip_segments,
+            flip_com_scan: self.flip_com_scan,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
+            offset_x: self.offset_x,
+            offset_y: self.offset_y,
+            clock: self.clock,
+            init_commands: self.init_commands,
+            brightness: self.brightness,
+            invert: self.invert,
+            display_on: self.display_on,
         }
     }
 
@@ -187,6 +322,22 @@ where
         self.into_mode(BufferedGraphicsMode::new())
     }
 
+    /// Convert the display into an experimental graphics mode with 4 levels of gray, which are
+    /// shown by sending frames to the display in turn.
+    ///
+    /// See [`GrayscaleMode`] for more information.
+    pub fn into_grayscale_mode(self) -> Ssd1306<DI, SIZE, GrayscaleMode<SIZE>> {
+        self.into_mode(GrayscaleMode::new())
+    }
+
+    /// Convert the display into a graphics mode with half of the resolution of the display,
+    /// which uses a quarter of the memory of the buffered graphics mode.
+    ///
+    /// See [`PixelDoubledMode`] for more information.
+    pub fn into_pixel_doubled_mode(self) -> Ssd1306<DI, SIZE, PixelDoubledMode<SIZE>> {
+        self.into_mode(PixelDoubledMode::new())
+    }
+
     /// Convert the display into a text-only, terminal-like mode.
     ///
     /// See [`TerminalMode`] for more information.
@@ -194,39 +345,262 @@ where
         self.into_mode(TerminalMode::new())
     }
 
+    /// Convert the display into a text-only, terminal-like mode which keeps a copy of the
+    /// characters on screen in system memory, using two bytes per character.
+    ///
+    /// Printed characters are only sent to the display if they differ from what is already on
+    /// screen. Repeatedly redrawing a screen of mostly unchanged text by moving the cursor back
+    /// with [`set_position`](Ssd1306::set_position) and overwriting it (rather than clearing the
+    /// screen) only transmits the characters that changed.
+    ///
+    /// See [`TerminalMode`] for more information.
+    pub fn into_terminal_mode_with_char_buffer(
+        self,
+    ) -> Ssd1306<DI, SIZE, TerminalMode<SIZE::CharBuffer>>
+    where
+        SIZE: TerminalDisplaySize,
+    {
+        self.into_mode(TerminalMode::with_char_buffer())
+    }
+
+    /// Select whether the display is powered from an external VCC supply instead of the
+    /// display driver's internal charge pump. This takes effect when the display is initialised.
+    ///
+    /// With an external supply, initialising the display leaves the charge pump disabled and
+    /// [`set_brightness`](Ssd1306::set_brightness) uses a longer phase 1 precharge period.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .with_external_vcc(true)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn with_external_vcc(mut self, external_vcc: bool) -> Self {
+        self.external_vcc = external_vcc;
+        self
+    }
+
+    /// Select the display driver's internal current reference instead of an external resistor,
+    /// optionally with a higher reference current, when the display is initialised. The
+    /// reference current affects the brightness of the display.
+    ///
+    /// This is only supported by the SSD1306B and some compatible display drivers. By default,
+    /// the current reference is only configured for display sizes which require it, such as
+    /// [`DisplaySize72x40`](size::DisplaySize72x40).
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .with_internal_iref(true, false)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn with_internal_iref(mut self, internal: bool, high_current: bool) -> Self {
+        self.iref = Some((internal, high_current));
+        self
+    }
+
+    /// Override the COM pin configuration chosen by the display size when the display is
+    /// initialised.
+    ///
+    /// `alternative` selects the alternative (true) or sequential (false) COM pin
+    /// configuration, and `left_right_remap` swaps the left and right halves of the COM pins.
+    /// Modules which show every other row blank or a vertically stretched image usually need a
+    /// different configuration than the one for their size.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+    ///     .with_com_pin_config(true, false)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn with_com_pin_config(mut self, alternative: bool, left_right_remap: bool) -> Self {
+        self.com_pins = Some((alternative, left_right_remap));
+        self
+    }
+
+    /// Reverse the segment remap derived from the display rotation, for modules with a panel
+    /// which is mirrored horizontally in the display's hardware orientation.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .with_flipped_segments(true)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn with_flipped_segments(mut self, flip: bool) -> Self {
+        self.flip_segments = flip;
+        self
+    }
+
+    /// Reverse the COM scan direction derived from the display rotation, for modules with a
+    /// panel which is mirrored vertically in the display's hardware orientation.
+    pub fn with_flipped_com_scan(mut self, flip: bool) -> Self {
+        self.flip_com_scan = flip;
+        self
+    }
+
+    /// Send extra commands when the display is initialised, after the standard init sequence and
+    /// before the display is turned on. Commands which change a setting override the value sent
+    /// by the standard init sequence, which allows adapting it for modules which need a
+    /// different configuration. Commands which change settings tracked by the driver, such as
+    /// the addressing mode, should not be sent.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{command::Command, prelude::*, Ssd1306};
+    ///
+    /// const EXTRA_INIT: &[Command] = &[Command::ComPinConfig(false, false), Command::Contrast(0x20)];
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+    ///     .with_init_commands(EXTRA_INIT)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// ```
+    pub fn with_init_commands(mut self, commands: &'static [Command]) -> Self {
+        self.init_commands = commands;
+        self
+    }
+
     /// Initialise the display in one of the available addressing modes.
-    pub fn init_with_addr_mode(&mut self, mode: AddrMode) -> Result<(), DisplayError> {
+    ///
+    /// This stops any hardware scrolling left active by a previous run of the firmware, and
+    /// resets the vertical scroll area to the whole display.
+    pub fn init_with_addr_mode(&mut self, mode: AddrMode) -> Result<(), DriverError> {
+        self.init_impl(mode, None)
+    }
+
+    /// Initialise the display, drawing a whole frame in the layout of the display memory to it
+    /// before turning it on if `splash` is given
+    pub(crate) fn init_impl(
+        &mut self,
+        mode: AddrMode,
+        splash: Option<&[u8]>,
+    ) -> Result<(), DriverError> {
+        self.with_operation(Operation::Init, |display| {
+            display.send_init_sequence(mode, splash)
+        })
+    }
+
+    /// Send the init sequence, see [`init_impl`](Self::init_impl)
+    fn send_init_sequence(
+        &mut self,
+        mode: AddrMode,
+        splash: Option<&[u8]>,
+    ) -> Result<(), DisplayError> {
         let rotation = self.rotation;
+        let brightness = Brightness::default();
+        let (phase1, phase2) = self.precharge_period(brightness);
+        let flip = self.scan_flip(rotation);
+        let (offset_x, offset_y) = self.memory_offsets();
+
+        // Pack the init sequence into as few transfers as possible
+        let mut iface = CommandBatch::new(&mut self.interface);
+
+        Command::DisplayOn(false).send(&mut iface)?;
+        // A scroll left active by a previous run of the firmware would keep moving the contents
+        Command::EnableScroll(false).send(&mut iface)?;
+        Command::VScrollArea(0, SIZE::PANEL_HEIGHT).send(&mut iface)?;
+        Command::DisplayClockDiv(self.clock.frequency, self.clock.divide_ratio - 1)
+            .send(&mut iface)?;
+        Command::Multiplex(SIZE::PANEL_HEIGHT - 1).send(&mut iface)?;
+        Command::DisplayOffset(0).send(&mut iface)?;
+        Command::StartLine(0).send(&mut iface)?;
+        Command::ZoomIn(self.zoom_in).send(&mut iface)?;
+        Command::ChargePump(!self.external_vcc).send(&mut iface)?;
+        Command::AddressMode(mode).send(&mut iface)?;
+
+        self.size.configure(&mut iface)?;
+        if let Some((alternative, remap)) = self.com_pins {
+            Command::ComPinConfig(alternative, remap).send(&mut iface)?;
+        }
+        if let Some((internal, high_current)) = self.iref {
+            Command::InternalIref(internal, high_current).send(&mut iface)?;
+        }
+        Self::send_rotation(&mut iface, rotation, flip)?;
 
-        Command::DisplayOn(false).send(&mut self.interface)?;
-        Command::DisplayClockDiv(0x8, 0x0).send(&mut self.interface)?;
-        Command::Multiplex(SIZE::HEIGHT - 1).send(&mut self.interface)?;
-        Command::DisplayOffset(0).send(&mut self.interface)?;
-        Command::StartLine(0).send(&mut self.interface)?;
-        // TODO: Ability to turn charge pump on/off
-        Command::ChargePump(true).send(&mut self.interface)?;
-        Command::AddressMode(mode).send(&mut self.interface)?;
-
-        self.size.configure(&mut self.interface)?;
-        self.set_rotation(rotation)?;
-
-        self.set_brightness(Brightness::default())?;
-        Command::VcomhDeselect(VcomhLevel::Auto).send(&mut self.interface)?;
-        Command::AllOn(false).send(&mut self.interface)?;
-        Command::Invert(false).send(&mut self.interface)?;
-        Command::EnableScroll(false).send(&mut self.interface)?;
-        Command::DisplayOn(true).send(&mut self.interface)?;
+        Command::PreChargePeriod(phase1, phase2).send(&mut iface)?;
+        Command::Contrast(brightness.contrast).send(&mut iface)?;
+        Command::VcomhDeselect(self.vcomh).send(&mut iface)?;
+        Command::AllOn(false).send(&mut iface)?;
+        Command::Invert(false).send(&mut iface)?;
+        self.init_commands
+            .iter()
+            .try_for_each(|command| command.send(&mut iface))?;
+
+        if let Some(splash) = splash {
+            // The frame is sent in one transfer in horizontal addressing mode
+            if mode != AddrMode::Horizontal {
+                Command::AddressMode(AddrMode::Horizontal).send(&mut iface)?;
+            }
+            Command::ColumnAddress(offset_x, offset_x + SIZE::WIDTH - 1).send(&mut iface)?;
+            Command::PageAddress(offset_y.into(), (offset_y + SIZE::HEIGHT - 1).into())
+                .send(&mut iface)?;
+
+            trace::data(splash);
+            iface.send_data(U8(splash))?;
+
+            if mode != AddrMode::Horizontal {
+                Command::AddressMode(mode).send(&mut iface)?;
+            }
+        }
+
+        Command::DisplayOn(true).send(&mut iface)?;
+        iface.flush()?;
 
         self.addr_mode = mode;
+        self.brightness = brightness;
+        self.invert = false;
+        self.display_on = true;
 
         Ok(())
     }
 
+    /// Set up the driver for a display which was already initialised, for example by a
+    /// bootloader, in one of the available addressing modes.
+    ///
+    /// Only the addressing mode and the rotation are sent to the display. It isn't turned off or
+    /// reconfigured, so its contents stay on screen.
+    pub fn attach_with_addr_mode(&mut self, mode: AddrMode) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            let rotation = display.rotation;
+            let flip = display.scan_flip(rotation);
+
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::AddressMode(mode).send(&mut iface)?;
+            Self::send_rotation(&mut iface, rotation, flip)?;
+            iface.flush()?;
+
+            display.addr_mode = mode;
+            display.display_on = true;
+
+            Ok(())
+        })
+    }
+
     /// Change the addressing mode
-    pub fn set_addr_mode(&mut self, mode: AddrMode) -> Result<(), DisplayError> {
-        Command::AddressMode(mode).send(&mut self.interface)?;
-        self.addr_mode = mode;
-        Ok(())
+    pub fn set_addr_mode(&mut self, mode: AddrMode) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::AddressMode(mode).send(&mut display.interface)?;
+            display.addr_mode = mode;
+            Ok(())
+        })
     }
 
     /// Send the data to the display for drawing at the current position in the framebuffer
@@ -240,22 +614,56 @@ where
         disp_width: usize,
         upper_left: (u8, u8),
         lower_right: (u8, u8),
-    ) -> Result<(), DisplayError> {
-        Self::flush_buffer_chunks(
-            &mut self.interface,
-            buffer,
-            disp_width,
-            upper_left,
-            lower_right,
-        )
+    ) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Self::flush_buffer_chunks(
+                &mut display.interface,
+                buffer,
+                disp_width,
+                upper_left,
+                lower_right,
+            )
+        })
     }
 
     /// Send a raw buffer to the display.
-    pub fn draw(&mut self, buffer: &[u8]) -> Result<(), DisplayError> {
-        self.interface.send_data(U8(buffer))
+    pub fn draw(&mut self, buffer: &[u8]) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            trace::data(buffer);
+            display.interface.send_data(U8(buffer))
+        })
+    }
+
+    /// Send raw command bytes to the display, for commands which aren't supported by this crate.
+    ///
+    /// This is available in every mode. The driver doesn't track the effect of the commands, so
+    /// commands which change settings managed by the driver, such as the addressing mode, may
+    /// confuse the current mode.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Set the contrast
+    /// display.send_raw_command(&[0x81, 0x40]).unwrap();
+    /// ```
+    pub fn send_raw_command(&mut self, bytes: &[u8]) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            // The I2C interface can send at most 7 command bytes at once. The display parses command
+            // bytes the same way when they are split across transfers.
+            bytes.chunks(7).try_for_each(|chunk| {
+                trace::commands(chunk);
+                display.interface.send_commands(U8(chunk))
+            })
+        })
     }
 
-    /// Get display dimensions, taking into account the current rotation of the display
+    /// Get display dimensions, taking into account the current rotation and zoom of the display
     ///
     /// ```rust
     /// # use ssd1306::test_helpers::StubInterface;
@@ -278,100 +686,560 @@ where
     /// assert_eq!(rotated_display.dimensions(), (64, 128));
     /// ```
     pub fn dimensions(&self) -> (u8, u8) {
+        // Zoom in shows every row twice, so only half as many rows fit on the display
+        let height = if self.zoom_in {
+            SIZE::HEIGHT / 2
+        } else {
+            SIZE::HEIGHT
+        };
+
         match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, height),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (height, SIZE::WIDTH),
         }
     }
 
+    /// Get the column and row of the display memory at which the display starts, as set with
+    /// [`set_offsets`](Ssd1306::set_offsets).
+    pub fn offsets(&self) -> (u8, u8) {
+        (self.offset_x, self.offset_y)
+    }
+
+    /// Change the column and row of the display memory at which the display starts, to calibrate
+    /// modules which connect the panel to other driver columns or rows than the
+    /// [display size](crate::size::DisplaySize) expects. The offsets are counted in the
+    /// display's hardware orientation and default to the `OFFSETX` and `OFFSETY` of the display
+    /// size.
+    ///
+    /// The display isn't redrawn. In buffered graphics mode the whole buffer is sent by the next
+    /// flush, and in terminal mode the display should be cleared. Columns and rows which are no
+    /// longer drawn to keep their contents.
+    ///
+    /// While [page flipping](Ssd1306::set_page_flipping) is enabled, both halves of the display
+    /// memory must still fit below the row offset, or the next flush returns
+    /// [`DisplayError::OutOfBoundsError`].
+    ///
+    /// The display memory is drawn to in pages of 8 rows, so the row offset must be a multiple of
+    /// 8. Returns [`DisplayError::OutOfBoundsError`] if it isn't, or if the display would extend
+    /// past the columns or rows of the display memory.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize72x40, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // This clone is wired one column to the right of the usual 72x40 module
+    /// display.set_offsets(29, 0).unwrap();
+    /// display.flush().unwrap();
+    ///
+    /// assert!(display.set_offsets(57, 0).is_err());
+    ///
+    /// // Rows can only be moved by whole pages
+    /// assert!(display.set_offsets(28, 3).is_err());
+    /// ```
+    pub fn set_offsets(&mut self, x: u8, y: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if u16::from(x) + u16::from(SIZE::WIDTH) > SIZE::DRIVER_COLS.into()
+                || u16::from(y) + u16::from(SIZE::HEIGHT) > SIZE::DRIVER_ROWS.into()
+                || y % 8 != 0
+            {
+                return Err(DisplayError::OutOfBoundsError);
+            }
+
+            display.offset_x = x;
+            display.offset_y = y;
+            Ok(())
+        })
+    }
+
+    /// Get the column and row of the display memory to draw the top left corner of the buffer
+    /// to, in the current scan directions
+    pub(crate) fn memory_offsets(&self) -> (u8, u8) {
+        let remap = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => true,
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate180 => false,
+        };
+
+        let offset_x = if remap != self.scan_flip(self.rotation).0 {
+            self.offset_x
+        } else {
+            // If segment remapping is flipped, we need to calculate the offset from the other
+            // edge of the display.
+            SIZE::DRIVER_COLS - SIZE::WIDTH - self.offset_x
+        };
+
+        (offset_x, self.offset_y)
+    }
+
     /// Get the display rotation.
     pub fn rotation(&self) -> DisplayRotation {
         self.rotation
     }
 
     /// Set the display rotation.
-    pub fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
-        self.rotation = rotation;
+    pub fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            display.rotation = rotation;
+
+            let flip = display.scan_flip(rotation);
+            Self::send_rotation(&mut display.interface, rotation, flip)
+        })
+    }
+
+    /// Get whether the display contents are flipped horizontally and vertically, as set with
+    /// [`set_flip`](Ssd1306::set_flip).
+    pub fn flip(&self) -> (bool, bool) {
+        (self.flip_x, self.flip_y)
+    }
+
+    /// Flip the display contents horizontally and/or vertically, for example for a display which
+    /// is viewed through a mirror.
+    ///
+    /// The flip is applied after the rotation, along the axes of the rotated display, and is done
+    /// by the display's scan direction so the contents of the buffer don't change. It is kept
+    /// when the rotation is changed or the display is initialised again.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Mirror the rotated display left to right
+    /// display.set_flip(true, false).unwrap();
+    /// assert_eq!(display.flip(), (true, false));
+    /// ```
+    pub fn set_flip(&mut self, flip_x: bool, flip_y: bool) -> Result<(), DriverError> {
+        self.flip_x = flip_x;
+        self.flip_y = flip_y;
+
+        self.set_rotation(self.rotation)
+    }
+
+    /// Get which of the segment remap and COM scan direction to reverse for a rotation, from the
+    /// panel overrides and the logical flip
+    fn scan_flip(&self, rotation: DisplayRotation) -> (bool, bool) {
+        // The horizontal axis of a rotated display runs along the COM lines
+        let (segments, com) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (self.flip_x, self.flip_y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (self.flip_y, self.flip_x),
+        };
+
+        (segments != self.flip_segments, com != self.flip_com_scan)
+    }
+
+    /// Send the segment remap and COM scan direction for a rotation, reversing them as selected
+    /// by `flip`
+    fn send_rotation(
+        iface: &mut impl WriteOnlyDataCommand,
+        rotation: DisplayRotation,
+        flip: (bool, bool),
+    ) -> Result<(), DisplayError> {
+        let (remap, reverse) = match rotation {
+            DisplayRotation::Rotate0 => (true, true),
+            DisplayRotation::Rotate90 => (false, true),
+            DisplayRotation::Rotate180 => (false, false),
+            DisplayRotation::Rotate270 => (true, false),
+        };
+
+        Command::SegmentRemap(remap != flip.0).send(iface)?;
+        Command::ReverseComDir(reverse != flip.1).send(iface)
+    }
+
+    /// Set mirror enabled/disabled.
+    ///
+    /// This is the same as [`set_flip(mirror, false)`](Ssd1306::set_flip).
+    pub fn set_mirror(&mut self, mirror: bool) -> Result<(), DriverError> {
+        self.set_flip(mirror, false)
+    }
+
+    /// Change the display brightness.
+    ///
+    /// If the precharge period was set with [`set_precharge`](Ssd1306::set_precharge), only the
+    /// contrast of `brightness` is used.
+    pub fn set_brightness(&mut self, brightness: Brightness) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            let (phase1, phase2) = display.precharge_period(brightness);
+
+            Command::PreChargePeriod(phase1, phase2).send(&mut display.interface)?;
+            Command::Contrast(brightness.contrast).send(&mut display.interface)?;
+            display.brightness = brightness;
+            Ok(())
+        })
+    }
+
+    /// Get the precharge phases used for a brightness level
+    fn precharge_period(&self, brightness: Brightness) -> (u8, u8) {
+        self.precharge.unwrap_or_else(|| {
+            // An external supply needs a longer first precharge phase
+            let phase1 = if self.external_vcc { 2 } else { 1 };
+
+            (phase1, brightness.precharge)
+        })
+    }
+
+    /// Send several commands to the display, packing them into as few transfers as possible.
+    ///
+    /// Over I2C, every transfer has its own start condition, address and control byte, so
+    /// batching commands reduces the time spent on the bus.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{command::Command, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display
+    ///     .send_commands(&[Command::Contrast(0x40), Command::Invert(true)])
+    ///     .unwrap();
+    /// ```
+    pub fn send_commands(&mut self, commands: &[Command]) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            let mut iface = CommandBatch::new(&mut display.interface);
+
+            commands
+                .iter()
+                .try_for_each(|command| command.send(&mut iface))?;
+
+            iface.flush()
+        })
+    }
+
+    /// Change the display clock, which sets the frame rate of the display.
+    ///
+    /// The clock is kept when the display is initialised again, so it can also be set before
+    /// initialising the display.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.set_display_clock(DisplayClock::FASTEST).unwrap();
+    /// ```
+    pub fn set_display_clock(&mut self, clock: DisplayClock) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::DisplayClockDiv(clock.frequency, clock.divide_ratio - 1)
+                .send(&mut display.interface)?;
+            display.clock = clock;
+            Ok(())
+        })
+    }
 
-        match rotation {
-            DisplayRotation::Rotate0 => {
-                Command::SegmentRemap(true).send(&mut self.interface)?;
-                Command::ReverseComDir(true).send(&mut self.interface)?;
+    /// Set the length of the two precharge phases of the display's pixels, in display clocks
+    /// (1-15). Tuning the precharge period can fix flickering, ghosting or uneven brightness on
+    /// some modules.
+    ///
+    /// The precharge period replaces the one chosen by [`Brightness`] levels, and is kept when
+    /// the display is initialised again, so it can also be set before initialising the display.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if either phase is not between 1 and 15.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.set_precharge(2, 2).unwrap();
+    /// display.init().unwrap();
+    /// ```
+    pub fn set_precharge(&mut self, phase1: u8, phase2: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if !(1..=15).contains(&phase1) || !(1..=15).contains(&phase2) {
+                return Err(DisplayError::OutOfBoundsError);
             }
-            DisplayRotation::Rotate90 => {
-                Command::SegmentRemap(false).send(&mut self.interface)?;
-                Command::ReverseComDir(true).send(&mut self.interface)?;
+
+            Command::PreChargePeriod(phase1, phase2).send(&mut display.interface)?;
+            display.precharge = Some((phase1, phase2));
+            Ok(())
+        })
+    }
+
+    /// Set the VCOMH deselect level, the voltage pixels are driven to when they are
+    /// off. Tuning the deselect level can fix ghosting or low contrast on some modules.
+    ///
+    /// The level is kept when the display is initialised again, so it can also be set before
+    /// initialising the display.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{command::VcomhLevel, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.set_vcomh_deselect(VcomhLevel::V077).unwrap();
+    /// ```
+    pub fn set_vcomh_deselect(&mut self, level: VcomhLevel) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::VcomhDeselect(level).send(&mut display.interface)?;
+            display.vcomh = level;
+            Ok(())
+        })
+    }
+
+    /// Fade the display out or make it blink by stepping the contrast every
+    /// `8 * (interval + 1)` frames, where `interval` is from 0 to 15.
+    ///
+    /// Fading and blinking are stopped with [`FadeMode::Disabled`], which restores the
+    /// previous contrast.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `interval` is greater than 15.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{command::FadeMode, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.set_fade(FadeMode::Blink, 3).unwrap();
+    /// ```
+    pub fn set_fade(&mut self, mode: FadeMode, interval: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if interval > 15 {
+                return Err(DisplayError::OutOfBoundsError);
             }
-            DisplayRotation::Rotate180 => {
-                Command::SegmentRemap(false).send(&mut self.interface)?;
-                Command::ReverseComDir(false).send(&mut self.interface)?;
+
+            Command::FadeBlink(mode, interval).send(&mut display.interface)
+        })
+    }
+
+    /// Get whether zoom in is enabled.
+    pub fn zoom_in(&self) -> bool {
+        self.zoom_in
+    }
+
+    /// Enable or disable zoom in, which shows every row of the top half of the display memory
+    /// twice. Content drawn for a display of half the height is shown at double height, for
+    /// example 128x32 content on a 128x64 display.
+    ///
+    /// Zoom in is only supported by displays using the alternative COM pin configuration, such
+    /// as [`DisplaySize128x64`](size::DisplaySize128x64).
+    ///
+    /// While zoomed in, [`dimensions`](Ssd1306::dimensions) returns the zoomed size, so drawing
+    /// in [`BufferedGraphicsMode`] and [`TerminalMode`] is limited to the visible rows. The
+    /// display contents are not redrawn, so the display should be cleared and redrawn after
+    /// changing the zoom. The setting is kept when the display is initialised again.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_zoom_in(true).unwrap();
+    /// assert_eq!(display.dimensions(), (128, 32));
+    ///
+    /// display.clear_buffer();
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_zoom_in(&mut self, zoom_in: bool) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::ZoomIn(zoom_in).send(&mut display.interface)?;
+            display.zoom_in = zoom_in;
+            Ok(())
+        })
+    }
+
+    /// Shift the rows of the display down by `offset` rows (0-63), wrapping around the rows of
+    /// the display driver. This moves which display row shows the first row of the display
+    /// memory, and can be used to compensate for modules whose contents appear shifted.
+    ///
+    /// The offset is reset to 0 when the display is initialised.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `offset` is greater than 63.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.set_display_offset(2).unwrap();
+    /// ```
+    pub fn set_display_offset(&mut self, offset: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if offset > 63 {
+                return Err(DisplayError::OutOfBoundsError);
             }
-            DisplayRotation::Rotate270 => {
-                Command::SegmentRemap(true).send(&mut self.interface)?;
-                Command::ReverseComDir(false).send(&mut self.interface)?;
+
+            Command::DisplayOffset(offset).send(&mut display.interface)
+        })
+    }
+
+    /// Set the row of the display memory (0-63) that is shown on the first row of the display.
+    /// Changing the start line pans the contents of the display memory vertically without
+    /// redrawing them.
+    ///
+    /// The start line is reset to 0 when the display is initialised.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `line` is greater than 63.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Pan the display contents up by 8 rows
+    /// display.set_start_line(8).unwrap();
+    /// ```
+    pub fn set_start_line(&mut self, line: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if line > 63 {
+                return Err(DisplayError::OutOfBoundsError);
             }
-        };
 
-        Ok(())
+            Command::StartLine(line).send(&mut display.interface)
+        })
     }
 
-    /// Set mirror enabled/disabled.
-    pub fn set_mirror(&mut self, mirror: bool) -> Result<(), DisplayError> {
-        if mirror {
-            match self.rotation {
-                DisplayRotation::Rotate0 => {
-                    Command::SegmentRemap(false).send(&mut self.interface)?;
-                    Command::ReverseComDir(true).send(&mut self.interface)?;
-                }
-                DisplayRotation::Rotate90 => {
-                    Command::SegmentRemap(false).send(&mut self.interface)?;
-                    Command::ReverseComDir(false).send(&mut self.interface)?;
-                }
-                DisplayRotation::Rotate180 => {
-                    Command::SegmentRemap(true).send(&mut self.interface)?;
-                    Command::ReverseComDir(false).send(&mut self.interface)?;
-                }
-                DisplayRotation::Rotate270 => {
-                    Command::SegmentRemap(true).send(&mut self.interface)?;
-                    Command::ReverseComDir(true).send(&mut self.interface)?;
-                }
-            };
-        } else {
-            self.set_rotation(self.rotation)?;
-        }
-        Ok(())
+    /// Only drive `rows` rows of the display (16-64, at most the height of the panel), which reduces
+    /// the power used by the display. The other rows are turned off. Use
+    /// [`set_display_offset`](Ssd1306::set_display_offset) to move the active rows, and
+    /// [`set_start_line`](Ssd1306::set_start_line) to choose the rows of the display memory they
+    /// show. Which rows of the panel stay active depends on the module's COM pin configuration
+    /// and the display rotation.
+    ///
+    /// Use [`reset_multiplex`](Ssd1306::reset_multiplex) to drive all rows again. All rows are
+    /// also driven again when the display is initialised.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `rows` is less than 16 or greater than the
+    /// height of the panel.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// // Only show the first 16 rows
+    /// display.set_multiplex(16).unwrap();
+    ///
+    /// // Show all rows again
+    /// display.reset_multiplex().unwrap();
+    /// ```
+    pub fn set_multiplex(&mut self, rows: u8) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if !(16..=SIZE::PANEL_HEIGHT).contains(&rows) {
+                return Err(DisplayError::OutOfBoundsError);
+            }
+
+            Command::Multiplex(rows - 1).send(&mut display.interface)
+        })
     }
 
-    /// Change the display brightness.
-    pub fn set_brightness(&mut self, brightness: Brightness) -> Result<(), DisplayError> {
-        Command::PreChargePeriod(1, brightness.precharge).send(&mut self.interface)?;
-        Command::Contrast(brightness.contrast).send(&mut self.interface)
+    /// Drive all rows of the display after reducing the number of rows with
+    /// [`set_multiplex`](Ssd1306::set_multiplex).
+    ///
+    /// This doesn't reset the display offset.
+    pub fn reset_multiplex(&mut self) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::Multiplex(SIZE::PANEL_HEIGHT - 1).send(&mut display.interface)
+        })
     }
 
     /// Turn the display on or off. The display can be drawn to and retains all
     /// of its memory even while off.
-    pub fn set_display_on(&mut self, on: bool) -> Result<(), DisplayError> {
-        Command::DisplayOn(on).send(&mut self.interface)
+    pub fn set_display_on(&mut self, on: bool) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::DisplayOn(on).send(&mut display.interface)?;
+            display.display_on = on;
+            Ok(())
+        })
+    }
+
+    /// Put the display to sleep by turning it and its charge pump off, which reduces its power
+    /// usage to a minimum. The display keeps its memory while asleep.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.sleep().unwrap();
+    /// // ...
+    /// display.wake().unwrap();
+    /// ```
+    pub fn sleep(&mut self) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::DisplayOn(false).send(&mut iface)?;
+            Command::ChargePump(false).send(&mut iface)?;
+            iface.flush()?;
+
+            display.display_on = false;
+
+            Ok(())
+        })
+    }
+
+    /// Wake the display from [`sleep`](Ssd1306::sleep) by turning its charge pump back on,
+    /// unless it uses an [external VCC supply](Ssd1306::with_external_vcc), and turning it on.
+    pub fn wake(&mut self) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::ChargePump(!display.external_vcc).send(&mut iface)?;
+            Command::DisplayOn(true).send(&mut iface)?;
+            iface.flush()?;
+
+            display.display_on = true;
+
+            Ok(())
+        })
     }
 
     /// Set the position in the framebuffer of the display limiting where any sent data should be
     /// drawn. This method can be used for changing the affected area on the screen as well
     /// as (re-)setting the start point of the next `draw` call.
-    pub fn set_draw_area(&mut self, start: (u8, u8), end: (u8, u8)) -> Result<(), DisplayError> {
-        Command::ColumnAddress(start.0, end.0.saturating_sub(1)).send(&mut self.interface)?;
+    pub fn set_draw_area(&mut self, start: (u8, u8), end: (u8, u8)) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            // Both ranges are sent in one transfer
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::ColumnAddress(start.0, end.0.saturating_sub(1)).send(&mut iface)?;
 
-        if self.addr_mode != AddrMode::Page {
-            Command::PageAddress(start.1.into(), (end.1.saturating_sub(1)).into())
-                .send(&mut self.interface)?;
-        }
+            if display.addr_mode != AddrMode::Page {
+                Command::PageAddress(start.1.into(), (end.1.saturating_sub(1)).into())
+                    .send(&mut iface)?;
+            }
 
-        Ok(())
+            iface.flush()
+        })
     }
 
     /// Set the column address in the framebuffer of the display where any sent data should be
     /// drawn.
-    pub fn set_column(&mut self, column: u8) -> Result<(), DisplayError> {
-        Command::ColStart(column).send(&mut self.interface)
+    pub fn set_column(&mut self, column: u8) -> Result<(), DriverError> {
+        self.with_context(|display| Command::ColStart(column).send(&mut display.interface))
     }
 
     /// Set the page address (row 8px high) in the framebuffer of the display where any sent data
@@ -379,17 +1247,248 @@ where
     ///
     /// Note that the parameter is in pixels, but the page will be set to the start of the 8px
     /// row which contains the passed-in row.
-    pub fn set_row(&mut self, row: u8) -> Result<(), DisplayError> {
-        Command::PageStart(row.into()).send(&mut self.interface)
+    pub fn set_row(&mut self, row: u8) -> Result<(), DriverError> {
+        self.with_context(|display| Command::PageStart(row.into()).send(&mut display.interface))
     }
 
     /// Set the screen pixel on/off inversion
-    pub fn set_invert(&mut self, invert: bool) -> Result<(), DisplayError> {
-        Command::Invert(invert).send(&mut self.interface)
+    pub fn set_invert(&mut self, invert: bool) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            Command::Invert(invert).send(&mut display.interface)?;
+            display.invert = invert;
+            Ok(())
+        })
+    }
+
+    /// Turn all pixels of the display on regardless of the contents of the display memory, or
+    /// show the contents of the display memory again. This is useful to test that all pixels of
+    /// a display work.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// display.set_all_on(true).unwrap();
+    /// // Check that every pixel lights up...
+    /// display.set_all_on(false).unwrap();
+    /// ```
+    pub fn set_all_on(&mut self, on: bool) -> Result<(), DriverError> {
+        self.with_context(|display| Command::AllOn(on).send(&mut display.interface))
+    }
+
+    /// Take a snapshot of the display settings cached by the driver: the rotation, addressing
+    /// mode, brightness, inversion, whether the display is on, and the zoom, precharge, VCOMH
+    /// deselect level and clock settings.
+    ///
+    /// The snapshot can be sent back to the display with
+    /// [`restore_state`](Ssd1306::restore_state), for example after the display was
+    /// reconfigured by another bus master, or by a new driver instance after the MCU woke up
+    /// from deep sleep.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    /// display.set_brightness(Brightness::DIM).unwrap();
+    /// display.set_invert(true).unwrap();
+    ///
+    /// let state = display.save_state();
+    /// assert_eq!(state.brightness(), Brightness::DIM);
+    ///
+    /// // Later, after another bus master used the display
+    /// display.restore_state(state).unwrap();
+    /// ```
+    pub fn save_state(&self) -> DisplayState {
+        DisplayState {
+            rotation: self.rotation,
+            addr_mode: self.addr_mode,
+            brightness: self.brightness,
+            invert: self.invert,
+            display_on: self.display_on,
+            zoom_in: self.zoom_in,
+            precharge: self.precharge,
+            vcomh: self.vcomh,
+            clock: self.clock,
+            flip: (self.flip_x, self.flip_y),
+        }
+    }
+
+    /// Send the settings of a snapshot taken with [`save_state`](Ssd1306::save_state) to the
+    /// display, and cache them in the driver.
+    ///
+    /// This only restores the settings. If the display lost power, it must be initialised first.
+    /// The contents of the display are not restored.
+    pub fn restore_state(&mut self, state: DisplayState) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            display.rotation = state.rotation;
+            display.addr_mode = state.addr_mode;
+            display.brightness = state.brightness;
+            display.invert = state.invert;
+            display.display_on = state.display_on;
+            display.zoom_in = state.zoom_in;
+            display.precharge = state.precharge;
+            display.vcomh = state.vcomh;
+            display.clock = state.clock;
+            display.flip_x = state.flip.0;
+            display.flip_y = state.flip.1;
+
+            let (phase1, phase2) = display.precharge_period(state.brightness);
+            let flip = display.scan_flip(state.rotation);
+
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::AddressMode(state.addr_mode).send(&mut iface)?;
+            Self::send_rotation(&mut iface, state.rotation, flip)?;
+            Command::DisplayClockDiv(state.clock.frequency, state.clock.divide_ratio - 1)
+                .send(&mut iface)?;
+            Command::ZoomIn(state.zoom_in).send(&mut iface)?;
+            Command::PreChargePeriod(phase1, phase2).send(&mut iface)?;
+            Command::Contrast(state.brightness.contrast).send(&mut iface)?;
+            Command::VcomhDeselect(state.vcomh).send(&mut iface)?;
+            Command::Invert(state.invert).send(&mut iface)?;
+            Command::DisplayOn(state.display_on).send(&mut iface)?;
+            iface.flush()
+        })
+    }
+
+    /// Continuously scroll the contents of the pages from `start` to `end` (inclusive)
+    /// horizontally, moving one column every `interval` frames.
+    ///
+    /// Pages and the scroll direction are in the display's hardware orientation, so the scroll
+    /// direction depends on the display rotation, and on rotated displays the contents scroll
+    /// vertically. Writing to the display while scrolling is active may corrupt its contents, so
+    /// scrolling should be stopped with [`stop_scroll`](Ssd1306::stop_scroll) before drawing.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `start` is after `end`.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{
+    ///     command::{HScrollDir, NFrames, Page},
+    ///     prelude::*,
+    ///     Ssd1306,
+    /// };
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// // Scroll a 16px high banner along the top of the screen
+    /// display
+    ///     .start_horizontal_scroll(HScrollDir::RightToLeft, Page::Page0, Page::Page1, NFrames::F5)
+    ///     .unwrap();
+    /// ```
+    pub fn start_horizontal_scroll(
+        &mut self,
+        direction: HScrollDir,
+        start: Page,
+        end: Page,
+        interval: NFrames,
+    ) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if start as u8 > end as u8 {
+                return Err(DisplayError::OutOfBoundsError);
+            }
+
+            // Scrolling must be deactivated before it is set up again
+            Command::EnableScroll(false).send(&mut display.interface)?;
+            Command::HScrollSetup(direction, start, end, interval).send(&mut display.interface)?;
+            Command::EnableScroll(true).send(&mut display.interface)
+        })
+    }
+
+    /// Continuously scroll the contents of the display vertically by `vertical_offset` rows
+    /// every `interval` frames, while also scrolling the pages from `start` to `end` (inclusive)
+    /// horizontally by one column.
+    ///
+    /// Like [`start_horizontal_scroll`](Ssd1306::start_horizontal_scroll), scrolling is in the
+    /// display's hardware orientation. A `vertical_offset` of 0 only scrolls horizontally.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `start` is after `end` or `vertical_offset`
+    /// is greater than 63.
+    pub fn start_diagonal_scroll(
+        &mut self,
+        direction: VHScrollDir,
+        start: Page,
+        end: Page,
+        interval: NFrames,
+        vertical_offset: u8,
+    ) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if start as u8 > end as u8 || vertical_offset > 63 {
+                return Err(DisplayError::OutOfBoundsError);
+            }
+
+            // Scrolling must be deactivated before it is set up again
+            Command::EnableScroll(false).send(&mut display.interface)?;
+            Command::VHScrollSetup(direction, start, end, interval, vertical_offset)
+                .send(&mut display.interface)?;
+            Command::EnableScroll(true).send(&mut display.interface)
+        })
+    }
+
+    /// Limit the vertical part of [`start_diagonal_scroll`](Ssd1306::start_diagonal_scroll) to
+    /// `scroll_rows` pixel rows below the first `fixed_rows` rows, which stay in place.
+    ///
+    /// Rows are in the display's hardware orientation. By default the whole display scrolls.
+    /// The vertical offset of the diagonal scroll must be smaller than `scroll_rows`.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the area doesn't fit on the display.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{
+    ///     command::{NFrames, Page, VHScrollDir},
+    ///     prelude::*,
+    ///     Ssd1306,
+    /// };
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// // Keep a 16px high header in place while the rest of the screen scrolls up
+    /// display.set_vertical_scroll_area(16, 48).unwrap();
+    /// display
+    ///     .start_diagonal_scroll(
+    ///         VHScrollDir::VerticalRight,
+    ///         Page::Page0,
+    ///         Page::Page0,
+    ///         NFrames::F2,
+    ///         1,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn set_vertical_scroll_area(
+        &mut self,
+        fixed_rows: u8,
+        scroll_rows: u8,
+    ) -> Result<(), DriverError> {
+        self.with_context(|display| {
+            if u16::from(fixed_rows) + u16::from(scroll_rows) > u16::from(SIZE::PANEL_HEIGHT) {
+                return Err(DisplayError::OutOfBoundsError);
+            }
+
+            Command::VScrollArea(fixed_rows, scroll_rows).send(&mut display.interface)
+        })
+    }
+
+    /// Stop hardware scrolling.
+    ///
+    /// The display contents are left where the scroll stopped, so the image needs to be redrawn
+    /// to restore it. Scrolling is also stopped when the display is initialised.
+    pub fn stop_scroll(&mut self) -> Result<(), DriverError> {
+        self.with_context(|display| Command::EnableScroll(false).send(&mut display.interface))
     }
 
     fn flush_buffer_chunks(
-        interface: &mut DI,
+        interface: &mut Counting<DI>,
         buffer: &[u8],
         disp_width: usize,
         upper_left: (u8, u8),
@@ -406,23 +1505,142 @@ where
         let page_lower = upper_left.0 as usize;
         let page_upper = lower_right.0 as usize;
 
-        buffer
-            .chunks(disp_width)
-            .skip(starting_page)
-            .take(num_pages)
-            .map(|s| &s[page_lower..page_upper])
-            .try_for_each(|c| interface.send_data(U8(c)))
+        // Whole pages follow each other in the buffer, so they are sent in a single transfer
+        if page_lower == 0 && page_upper == disp_width {
+            let start = (starting_page * disp_width).min(buffer.len());
+            let end = ((starting_page + num_pages) * disp_width).min(buffer.len());
+
+            trace::data(&buffer[start..end]);
+            return interface.send_data(U8(&buffer[start..end]));
+        }
+
+        Self::send_gathered(
+            interface,
+            disp_width,
+            starting_page..starting_page + num_pages,
+            page_lower..page_upper,
+            |range, chunk| chunk.copy_from_slice(&buffer[range]),
+        )
+    }
+
+    /// Send the columns `columns` of the pages `pages` in as few transfers as possible.
+    ///
+    /// The display continues with the next page after the last column of the draw area, so the
+    /// parts of the pages are copied one after the other into a chunk by `fill`, which is called
+    /// with the range of the buffer to copy, and the chunk is sent when it is full.
+    fn send_gathered(
+        interface: &mut Counting<DI>,
+        disp_width: usize,
+        pages: core::ops::Range<usize>,
+        columns: core::ops::Range<usize>,
+        mut fill: impl FnMut(core::ops::Range<usize>, &mut [u8]),
+    ) -> Result<(), DisplayError> {
+        let mut chunk = [0; 128];
+        let mut len = 0;
+
+        for page in pages {
+            let mut range = page * disp_width + columns.start..page * disp_width + columns.end;
+
+            while !range.is_empty() {
+                let n = range.len().min(chunk.len() - len);
+                fill(range.start..range.start + n, &mut chunk[len..len + n]);
+                range.start += n;
+                len += n;
+
+                if len == chunk.len() {
+                    trace::data(&chunk);
+                    interface.send_data(U8(&chunk))?;
+                    len = 0;
+                }
+            }
+        }
+
+        if len > 0 {
+            trace::data(&chunk[..len]);
+            interface.send_data(U8(&chunk[..len]))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the counters of the transfers to the display since the display was created, or since
+    /// they were last reset with [`reset_stats`](Self::reset_stats).
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.reset_stats();
+    ///
+    /// display.set_pixel(0, 0, true);
+    /// display.flush().unwrap();
+    ///
+    /// let stats = display.stats();
+    /// assert_eq!(stats.flushes(), 1);
+    /// assert!(stats.largest_transaction() <= 1024);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        self.interface.stats
+    }
+
+    /// Reset the counters of the transfers to the display.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.interface.stats = Stats::default();
+    }
+
+    /// Get a reference to the contained interface.
+    pub fn interface(&self) -> &DI {
+        &self.interface.iface
+    }
+
+    /// Get a mutable reference to the contained interface.
+    pub fn interface_mut(&mut self) -> &mut DI {
+        &mut self.interface.iface
     }
 
     /// Release the contained interface.
     pub fn release(self) -> DI {
-        self.interface
+        self.interface.iface
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE> {
+    /// Run `f`, adding `operation` and the failed transfer to its error
+    pub(crate) fn with_operation<T>(
+        &mut self,
+        operation: Operation,
+        f: impl FnOnce(&mut Self) -> Result<T, DisplayError>,
+    ) -> Result<T, DriverError> {
+        let previous = core::mem::replace(&mut self.interface.operation, operation);
+        self.interface.last_error = None;
+
+        let result =
+            f(self).map_err(|error| DriverError::new(error, operation, self.interface.last_error));
+        self.interface.operation = previous;
+
+        result
+    }
+
+    /// Run `f`, adding the current operation and the failed transfer to its error
+    pub(crate) fn with_context<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, DisplayError>,
+    ) -> Result<T, DriverError> {
+        self.with_operation(self.interface.operation, f)
     }
 }
 
 // SPI-only reset
 impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE> {
     /// Reset the display.
+    ///
+    /// This uses the default [`ResetConfig`], for an active low reset pin.
     pub fn reset<RST, DELAY>(
         &mut self,
         rst: &mut RST,
@@ -432,18 +1650,148 @@ impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE> {
         RST: OutputPin,
         DELAY: DelayMs<u8>,
     {
-        fn inner_reset<RST, DELAY>(rst: &mut RST, delay: &mut DELAY) -> Result<(), RST::Error>
+        self.reset_with_config(rst, delay, ResetConfig::default())
+    }
+
+    /// Reset the display with a custom reset pin polarity and pulse timing.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::{DelayStub, PinStub, StubInterface};
+    /// # let interface = StubInterface;
+    /// # let mut rst = PinStub;
+    /// # let mut delay = DelayStub;
+    /// use ssd1306::{prelude::*, reset::ResetConfig, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    ///
+    /// display
+    ///     .reset_with_config(&mut rst, &mut delay, ResetConfig::new().active_high(true))
+    ///     .unwrap();
+    /// display.init().unwrap();
+    /// ```
+    pub fn reset_with_config<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+        config: ResetConfig,
+    ) -> Result<(), Error<Infallible, RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayMs<u8>,
+    {
+        fn inner_reset<RST, DELAY>(
+            rst: &mut RST,
+            delay: &mut DELAY,
+            config: ResetConfig,
+        ) -> Result<(), RST::Error>
         where
             RST: OutputPin,
             DELAY: DelayMs<u8>,
         {
-            rst.set_high()?;
-            delay.delay_ms(1);
-            rst.set_low()?;
-            delay.delay_ms(10);
-            rst.set_high()
+            let mut set_active = |active: bool| {
+                if active == config.active_high {
+                    rst.set_high()
+                } else {
+                    rst.set_low()
+                }
+            };
+
+            set_active(false)?;
+            delay.delay_ms(config.idle_ms);
+            set_active(true)?;
+            delay.delay_ms(config.pulse_ms);
+            set_active(false)?;
+            delay.delay_ms(config.settle_ms);
+
+            Ok(())
         }
 
-        inner_reset(rst, delay).map_err(Error::Pin)
+        inner_reset(rst, delay, config).map_err(Error::Pin)
+    }
+}
+
+// Reset for modules without a reset pin
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+    Self: DisplayConfig,
+    <Self as DisplayConfig>::Error: From<DriverError>,
+{
+    /// Reset the display using commands, for modules without a reset pin.
+    ///
+    /// The display and its charge pump are turned off, and after waiting for the panel voltage
+    /// to discharge the display is initialised again in the current mode with
+    /// [`DisplayConfig::init`]. This then waits for the charge pump to reach the panel voltage,
+    /// taking about 200 ms in total.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::{DelayStub, StubInterface};
+    /// # let interface = StubInterface;
+    /// # let mut delay = DelayStub;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Recover a display which shows garbage
+    /// display.soft_reset(&mut delay).unwrap();
+    /// ```
+    pub fn soft_reset<DELAY>(
+        &mut self,
+        delay: &mut DELAY,
+    ) -> Result<(), <Self as DisplayConfig>::Error>
+    where
+        DELAY: DelayMs<u8>,
+    {
+        self.with_context(|display| {
+            let mut iface = CommandBatch::new(&mut display.interface);
+            Command::DisplayOn(false).send(&mut iface)?;
+            Command::ChargePump(false).send(&mut iface)?;
+            iface.flush()
+        })?;
+        delay.delay_ms(100);
+
+        self.init()?;
+        delay.delay_ms(100);
+
+        Ok(())
+    }
+
+    /// Reset the display with its reset pin and then initialise it in the current mode with
+    /// [`DisplayConfig::init`].
+    ///
+    /// This is the same as calling [`reset`](Ssd1306::reset) followed by `init`. With the `async`
+    /// feature, `init_with_reset_async` does the same with an async delay for the interfaces of
+    /// the `hal_async` module.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::{DelayStub, PinStub, StubInterface};
+    /// # let interface = StubInterface;
+    /// # let mut rst = PinStub;
+    /// # let mut delay = DelayStub;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    ///
+    /// display.init_with_reset(&mut rst, &mut delay).unwrap();
+    /// ```
+    pub fn init_with_reset<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<<Self as DisplayConfig>::Error, RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayMs<u8>,
+    {
+        self.reset(rst, delay).map_err(|err| match err {
+            Error::Pin(err) => Error::Pin(err),
+            Error::Comm(never) => match never {},
+        })?;
+
+        self.init().map_err(Error::Comm)
     }
 }
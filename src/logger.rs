@@ -0,0 +1,141 @@
+//! A [`log`](https://docs.rs/log) logger which prints records to a display in terminal mode.
+//!
+//! This module is enabled by the `logger` feature. Records are printed on a new line, prefixed
+//! with the first letter of their level (`E`, `W`, `I`, `D` or `T`), and the screen scrolls up
+//! when it's full. The display must keep a shadow character buffer so it can be scrolled, see
+//! [`into_terminal_mode_with_char_buffer`](crate::Ssd1306::into_terminal_mode_with_char_buffer).
+//!
+//! ```rust,no_run
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use log::LevelFilter;
+//! use ssd1306::{
+//!     logger::DisplayLogger,
+//!     mode::{Cell, TerminalMode},
+//!     prelude::*,
+//!     Ssd1306,
+//! };
+//!
+//! type Display = Ssd1306<StubInterface, DisplaySize128x64, TerminalMode<[Cell; 128]>>;
+//!
+//! static LOGGER: DisplayLogger<Display> = DisplayLogger::new(LevelFilter::Info);
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_terminal_mode_with_char_buffer();
+//! display.init().unwrap();
+//! display.clear().unwrap();
+//!
+//! LOGGER.install(display).unwrap();
+//!
+//! log::info!("Hello, world!");
+//! ```
+//!
+//! Access to the display is guarded by a
+//! [`critical-section`](https://docs.rs/critical-section) mutex, so an implementation of
+//! `critical-section` must be linked into the application, for example by enabling the
+//! `critical-section-single-core` feature of `cortex-m`. Records logged while another record is
+//! being printed, for example from an interrupt, are dropped.
+
+use crate::{
+    mode::{Cell, TerminalDisplaySize, TerminalMode},
+    Ssd1306,
+};
+use core::{cell::RefCell, fmt::Write};
+use critical_section::Mutex;
+use display_interface::WriteOnlyDataCommand;
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// A logger which prints records to a display in terminal mode.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug)]
+pub struct DisplayLogger<D> {
+    display: Mutex<RefCell<Option<D>>>,
+    level: LevelFilter,
+}
+
+impl<D> DisplayLogger<D> {
+    /// Create a new logger which prints records up to the given level, with no display attached.
+    pub const fn new(level: LevelFilter) -> Self {
+        Self {
+            display: Mutex::new(RefCell::new(None)),
+            level,
+        }
+    }
+
+    /// Detach the display from the logger, returning it.
+    pub fn detach(&self) -> Option<D> {
+        critical_section::with(|cs| self.display.borrow(cs).take())
+    }
+}
+
+impl<DI, SIZE, const N: usize> DisplayLogger<Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>>
+where
+    DI: WriteOnlyDataCommand + Send,
+    SIZE: TerminalDisplaySize + Send,
+{
+    /// Attach a display to the logger, returning the previously attached display.
+    ///
+    /// Scrolling is enabled on the display.
+    pub fn attach(
+        &self,
+        mut display: Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>,
+    ) -> Option<Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>> {
+        display.set_scrolling(true);
+
+        critical_section::with(|cs| self.display.borrow(cs).replace(Some(display)))
+    }
+
+    /// Attach a display to the logger and register it as the logger used by the `log` crate.
+    pub fn install(
+        &'static self,
+        display: Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>,
+    ) -> Result<(), SetLoggerError> {
+        self.attach(display);
+        log::set_logger(self)?;
+        log::set_max_level(self.level);
+
+        Ok(())
+    }
+}
+
+impl<DI, SIZE, const N: usize> Log for DisplayLogger<Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>>
+where
+    DI: WriteOnlyDataCommand + Send,
+    SIZE: TerminalDisplaySize + Send,
+{
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        // Tracing the commands sent to the display would print records forever
+        metadata.level() <= self.level && metadata.target() != crate::trace::TARGET
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        // Take the display out of the mutex so interrupts aren't blocked while printing
+        let mut display = match critical_section::with(|cs| self.display.borrow(cs).take()) {
+            Some(display) => display,
+            None => return,
+        };
+
+        let prefix = match record.level() {
+            Level::Error => 'E',
+            Level::Warn => 'W',
+            Level::Info => 'I',
+            Level::Debug => 'D',
+            Level::Trace => 'T',
+        };
+
+        // Start a new line, unless the cursor is already at the start of one
+        if !matches!(display.position(), Ok((0, _))) {
+            let _ = display.write_char('\n');
+        }
+        let _ = write!(display, "{} {}", prefix, record.args());
+
+        critical_section::with(|cs| self.display.borrow(cs).replace(Some(display)));
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,352 @@
+//! An interface that records everything sent to it, for unit testing.
+//!
+//! [`MockInterface`] records every command and data write, so that tests can check the exact
+//! bytes sent by an initialization sequence, and decodes them into a [`Simulator`] so that tests
+//! can check what the display would show.
+//!
+//! ```rust
+//! use ssd1306::{mock::MockInterface, prelude::*, Ssd1306};
+//!
+//! let interface = MockInterface::new(DisplaySize128x32);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // The last command of the initialization turns the display on
+//! assert_eq!(display.interface().command_bytes().last(), Some(&0xAF));
+//!
+//! display.flush().unwrap();
+//! display.interface_mut().clear_writes();
+//!
+//! // Only the changed byte is sent
+//! display.set_pixel(1, 2, true);
+//! display.flush().unwrap();
+//!
+//! assert_eq!(display.interface().data_bytes(), [0b100]);
+//! assert!(display.interface().simulator().pixel(1, 2));
+//! ```
+//!
+//! # Golden images
+//!
+//! The simulated panel can be compared with an expected image, to catch regressions in the
+//! rendering of an application in tests on the host. Expected images are either drawn with
+//! `#` for lit and `.` for unlit pixels, the format printed by `{:#}` of the
+//! [`Simulator`], or frames in the layout of the display memory, which
+//! [`include_frame!`](crate::include_frame) reads from PBM images. When the images don't match,
+//! the [`ImageDiff`] shows which pixels are different.
+//!
+//! ```rust
+//! use ssd1306::{mock::MockInterface, prelude::*, Ssd1306};
+//!
+//! let interface = MockInterface::new(DisplaySize96x16);
+//! let mut display = Ssd1306::new(interface, DisplaySize96x16, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Draw a diagonal line
+//! for i in 0..16 {
+//!     display.set_pixel(i, i, true);
+//! }
+//! display.flush().unwrap();
+//!
+//! let expected: String = (0..16)
+//!     .map(|y| (0..96).map(|x| if x == y { '#' } else { '.' }).collect::<String>() + "\n")
+//!     .collect();
+//! display.interface().assert_image(&expected);
+//!
+//! // A different image, with one pixel less
+//! let diff = display.interface().compare(&expected.replacen('#', ".", 1)).unwrap_err();
+//! assert_eq!(diff.differences(), 1);
+//! ```
+
+use core::fmt;
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use std::vec::Vec;
+
+use crate::{pbm::frame_len, simulator::Simulator, size::DisplaySize};
+
+/// A single write to a [`MockInterface`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Write {
+    /// Command bytes sent with [`send_commands`](WriteOnlyDataCommand::send_commands)
+    Command(Vec<u8>),
+
+    /// Data bytes sent with [`send_data`](WriteOnlyDataCommand::send_data)
+    Data(Vec<u8>),
+}
+
+/// An interface that records all writes and decodes them into a simulated display.
+///
+/// See the [module level documentation](crate::mock) for an example.
+#[derive(Debug, Clone)]
+pub struct MockInterface {
+    writes: Vec<Write>,
+    simulator: Simulator,
+}
+
+impl MockInterface {
+    /// Create a new mock interface for a display of the given size.
+    pub fn new<SIZE: DisplaySize>(size: SIZE) -> Self {
+        Self {
+            writes: Vec::new(),
+            simulator: Simulator::new(size),
+        }
+    }
+
+    /// Get all writes since the interface was created or last cleared, in order.
+    pub fn writes(&self) -> &[Write] {
+        &self.writes
+    }
+
+    /// Get the bytes of all command writes, concatenated.
+    pub fn command_bytes(&self) -> Vec<u8> {
+        self.writes
+            .iter()
+            .filter_map(|write| match write {
+                Write::Command(bytes) => Some(bytes.as_slice()),
+                Write::Data(_) => None,
+            })
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// Get the bytes of all data writes, concatenated.
+    pub fn data_bytes(&self) -> Vec<u8> {
+        self.writes
+            .iter()
+            .filter_map(|write| match write {
+                Write::Data(bytes) => Some(bytes.as_slice()),
+                Write::Command(_) => None,
+            })
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// Forget the recorded writes.
+    ///
+    /// This does not change the state of the simulated display.
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+
+    /// Get the simulated display the writes are decoded into.
+    pub fn simulator(&self) -> &Simulator {
+        &self.simulator
+    }
+
+    /// Compare the simulated panel with an image drawn with `#` for lit and `.` for unlit pixels.
+    ///
+    /// Each line of `expected` is a row of the image. Whitespace around the lines and empty lines
+    /// are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `expected` contains other characters, or if its rows have different lengths.
+    pub fn compare(&self, expected: &str) -> Result<(), ImageDiff> {
+        let mut width = None;
+        let mut height = 0;
+        let mut pixels = Vec::new();
+
+        for line in expected
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let start = pixels.len();
+            pixels.extend(line.chars().map(|c| match c {
+                '#' => true,
+                '.' => false,
+                _ => panic!("invalid pixel {:?} in expected image", c),
+            }));
+
+            let line_width = pixels.len() - start;
+            if *width.get_or_insert(line_width) != line_width {
+                panic!("rows of the expected image have different lengths");
+            }
+            height += 1;
+        }
+
+        self.compare_pixels(width.unwrap_or(0), height, pixels)
+    }
+
+    /// Compare the simulated panel with a frame in the layout of the display memory, as read by
+    /// [`include_frame!`](crate::include_frame) or [`frame_from_pbm`](crate::pbm::frame_from_pbm).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `expected` doesn't match the size of the panel.
+    pub fn compare_frame(&self, expected: &[u8]) -> Result<(), ImageDiff> {
+        let (width, height) = self.simulator.size();
+        if expected.len() != frame_len(width, height) {
+            panic!("frame length doesn't match the size of the display");
+        }
+
+        let (width, height) = (usize::from(width), usize::from(height));
+        let pixels = (0..width * height)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+                expected[y / 8 * width + x] & (1 << (y % 8)) != 0
+            })
+            .collect();
+
+        self.compare_pixels(width, height, pixels)
+    }
+
+    /// Assert that the simulated panel matches an image drawn with `#` and `.`.
+    ///
+    /// See [`compare`](Self::compare) for the format of the image.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a readable diff if the panel doesn't match the image.
+    #[track_caller]
+    pub fn assert_image(&self, expected: &str) {
+        if let Err(diff) = self.compare(expected) {
+            panic!("display doesn't match the expected image\n{}", diff);
+        }
+    }
+
+    /// Assert that the simulated panel matches a frame in the layout of the display memory.
+    ///
+    /// See [`compare_frame`](Self::compare_frame) for the format of the frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a readable diff if the panel doesn't match the frame.
+    #[track_caller]
+    pub fn assert_frame(&self, expected: &[u8]) {
+        if let Err(diff) = self.compare_frame(expected) {
+            panic!("display doesn't match the expected frame\n{}", diff);
+        }
+    }
+
+    /// Compare the simulated panel with the pixels of an image, in row-major order.
+    fn compare_pixels(
+        &self,
+        width: usize,
+        height: usize,
+        expected: Vec<bool>,
+    ) -> Result<(), ImageDiff> {
+        let (panel_width, panel_height) = self.simulator.size();
+        let actual = (0..panel_height)
+            .flat_map(|y| (0..panel_width).map(move |x| (x, y)))
+            .map(|(x, y)| self.simulator.pixel(x, y))
+            .collect::<Vec<_>>();
+
+        let diff = ImageDiff {
+            size: (usize::from(panel_width), usize::from(panel_height)),
+            expected_size: (width, height),
+            expected,
+            actual,
+        };
+
+        if diff.size == diff.expected_size && diff.differences() == 0 {
+            Ok(())
+        } else {
+            Err(diff)
+        }
+    }
+
+    /// Collect the bytes of a write.
+    fn bytes(format: DataFormat<'_>) -> Result<Vec<u8>, DisplayError> {
+        match format {
+            DataFormat::U8(bytes) => Ok(bytes.to_vec()),
+            DataFormat::U8Iter(bytes) => Ok(bytes.collect()),
+            _ => Err(DisplayError::DataFormatNotImplemented),
+        }
+    }
+}
+
+impl WriteOnlyDataCommand for MockInterface {
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = Self::bytes(cmd)?;
+        self.simulator.send_commands(DataFormat::U8(&bytes))?;
+        self.writes.push(Write::Command(bytes));
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = Self::bytes(buf)?;
+        self.simulator.send_data(DataFormat::U8(&bytes))?;
+        self.writes.push(Write::Data(bytes));
+
+        Ok(())
+    }
+}
+
+/// The difference between the simulated panel and an expected image.
+///
+/// The [`Display`](fmt::Display) implementation draws the panel with `#` for lit and `.` for unlit
+/// pixels which match the image, `+` for pixels which are lit but shouldn't be, and `-` for
+/// pixels which should be lit but aren't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDiff {
+    size: (usize, usize),
+    expected_size: (usize, usize),
+    expected: Vec<bool>,
+    actual: Vec<bool>,
+}
+
+impl ImageDiff {
+    /// Get the width and height of the panel.
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Get the width and height of the expected image.
+    pub fn expected_size(&self) -> (usize, usize) {
+        self.expected_size
+    }
+
+    /// Get the number of pixels which are different, or 0 if the sizes are different.
+    pub fn differences(&self) -> usize {
+        if self.size != self.expected_size {
+            return 0;
+        }
+
+        self.actual
+            .iter()
+            .zip(&self.expected)
+            .filter(|(actual, expected)| actual != expected)
+            .count()
+    }
+}
+
+impl fmt::Display for ImageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.size != self.expected_size {
+            return writeln!(
+                f,
+                "expected a {}x{} image, but the display is {}x{}",
+                self.expected_size.0, self.expected_size.1, self.size.0, self.size.1
+            );
+        }
+
+        writeln!(
+            f,
+            "{} pixels differ (+ lit but expected unlit, - unlit but expected lit):",
+            self.differences()
+        )?;
+
+        for (actual, expected) in self
+            .actual
+            .chunks(self.size.0)
+            .zip(self.expected.chunks(self.size.0))
+        {
+            for (&actual, &expected) in actual.iter().zip(expected) {
+                f.write_str(match (actual, expected) {
+                    (true, true) => "#",
+                    (false, false) => ".",
+                    (true, false) => "+",
+                    (false, true) => "-",
+                })?;
+            }
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
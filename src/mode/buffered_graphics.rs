@@ -2,10 +2,16 @@
 
 use crate::{
     command::AddrMode,
+    error::{DriverError, Operation},
+    layer::Layer,
+    raster_op::RasterOp,
     rotation::DisplayRotation,
     size::{DisplaySize, NewZeroed},
+    stats::Counting,
+    status::{Mismatch, ReadMemory, ReadStatus},
     Ssd1306,
 };
+use core::fmt;
 use display_interface::{DisplayError, WriteOnlyDataCommand};
 
 /// Buffered graphics mode.
@@ -14,6 +20,10 @@ use display_interface::{DisplayError, WriteOnlyDataCommand};
 /// buffer is drawn to by [`set_pixel`](Ssd1306::set_pixel) commands or
 /// [`embedded-graphics`](https://docs.rs/embedded-graphics) commands. The display can then be
 /// updated using the [`flush`](Ssd1306::flush) method.
+///
+/// On displays which use at most half of the rows of the display memory, such as 128x32 modules,
+/// [page flipping](Ssd1306::set_page_flipping) can be enabled to draw each frame to the hidden
+/// half of the display memory before showing it, so updates never tear.
 #[derive(Clone, Debug)]
 pub struct BufferedGraphicsMode<SIZE>
 where
@@ -24,6 +34,21 @@ where
     max_x: u8,
     min_y: u8,
     max_y: u8,
+    /// Area changed in the frame before the last flush, which is missing from the hidden half
+    /// of the display memory when page flipping
+    prev_min_x: u8,
+    prev_max_x: u8,
+    prev_min_y: u8,
+    prev_max_y: u8,
+    page_flipping: bool,
+    /// The display shows the second half of the display memory
+    showing_back: bool,
+    /// `BinaryColor::On` is drawn as a dark pixel
+    invert_colors: bool,
+    /// Offsets in the display memory the buffer was last sent to
+    offsets: (u8, u8),
+    /// How drawn pixels are combined with the buffer
+    raster_op: RasterOp,
 }
 
 impl<SIZE> BufferedGraphicsMode<SIZE>
@@ -38,6 +63,38 @@ where
             max_x: 0,
             min_y: 255,
             max_y: 0,
+            prev_min_x: 255,
+            prev_max_x: 0,
+            prev_min_y: 255,
+            prev_max_y: 0,
+            page_flipping: false,
+            showing_back: false,
+            invert_colors: false,
+            offsets: (SIZE::OFFSETX, SIZE::OFFSETY),
+            raster_op: RasterOp::Replace,
+        }
+    }
+}
+
+/// Get the index of the byte and the bit in a buffer in the layout of the display memory which
+/// hold a pixel
+pub(crate) fn pixel_index<SIZE: DisplaySize>(
+    rotation: DisplayRotation,
+    x: u32,
+    y: u32,
+) -> (usize, u32) {
+    match rotation {
+        DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+            let idx = ((y as usize) / 8 * SIZE::WIDTH as usize) + (x as usize);
+            let bit = y % 8;
+
+            (idx, bit)
+        }
+        DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+            let idx = ((x as usize) / 8 * SIZE::WIDTH as usize) + (y as usize);
+            let bit = x % 8;
+
+            (idx, bit)
         }
     }
 }
@@ -47,20 +104,53 @@ where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
 {
-    type Error = DisplayError;
+    type Error = DriverError;
 
     /// Set the display rotation
     ///
     /// This method resets the cursor but does not clear the screen.
-    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DriverError> {
         self.set_rotation(rot)
     }
 
     /// Initialise and clear the display in graphics mode.
-    fn init(&mut self) -> Result<(), DisplayError> {
+    fn init(&mut self) -> Result<(), DriverError> {
         self.clear_impl(false);
+        // Initialising resets the start line, so the contents of the hidden half are unknown
+        self.mode.showing_back = false;
+        self.mark_prev_frame_dirty();
         self.init_with_addr_mode(AddrMode::Horizontal)
     }
+
+    /// Attach to an initialised display in graphics mode without clearing it.
+    ///
+    /// The contents of the display are kept until they are overwritten by a flush, which only
+    /// sends the parts of the buffer that were drawn to.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    ///
+    /// // The bootloader already initialised the display and drew a logo
+    /// display.attach().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    /// display.flush().unwrap();
+    /// ```
+    fn attach(&mut self) -> Result<(), DriverError> {
+        // Nothing has been drawn to the buffer yet
+        self.mode.min_x = 255;
+        self.mode.max_x = 0;
+        self.mode.min_y = 255;
+        self.mode.max_y = 0;
+        self.mode.showing_back = false;
+        self.mark_prev_frame_dirty();
+        self.attach_with_addr_mode(AddrMode::Horizontal)
+    }
 }
 
 impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
@@ -78,15 +168,391 @@ where
         self.mode.max_y = height - 1;
     }
 
+    /// Mark the whole display as changed in both halves of the display memory without clearing
+    /// the buffer, so the next flushes redraw everything
+    fn mark_all_dirty(&mut self) {
+        let (width, height) = self.dimensions();
+        self.mode.min_x = 0;
+        self.mode.max_x = width - 1;
+        self.mode.min_y = 0;
+        self.mode.max_y = height - 1;
+        self.mark_prev_frame_dirty();
+    }
+
+    /// Mark the whole display as changed in the previous frame, so the next two flushes while
+    /// page flipping redraw both halves of the display memory
+    fn mark_prev_frame_dirty(&mut self) {
+        let (width, height) = self.dimensions();
+        self.mode.prev_min_x = 0;
+        self.mode.prev_max_x = width - 1;
+        self.mode.prev_min_y = 0;
+        self.mode.prev_max_y = height - 1;
+    }
+
+    /// Initialise the display like [`init`](DisplayConfig::init), and draw a splash screen, such
+    /// as a logo, to it before turning it on, so the first frame on screen is the splash screen
+    /// rather than the random contents of the display memory.
+    ///
+    /// `splash` is a whole frame in the layout of the [buffer](Ssd1306::buffer), which is copied
+    /// to the buffer. Returns [`DisplayError::OutOfBoundsError`] without initialising the display
+    /// if `splash` is too short.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{include_frame, prelude::*, Ssd1306};
+    ///
+    /// static LOGO: [u8; 1024] = include_frame!(DisplaySize128x64, "../../examples/rust_128x64.pbm");
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init_with_splash(&LOGO).unwrap();
+    ///
+    /// // Load the application while the logo is shown...
+    ///
+    /// display.clear_buffer();
+    /// display.flush().unwrap();
+    /// ```
+    pub fn init_with_splash(&mut self, splash: &[u8]) -> Result<(), DriverError> {
+        let buffer = self.mode.buffer.as_mut();
+        let splash = splash
+            .get(..buffer.len())
+            .ok_or(DisplayError::OutOfBoundsError)?;
+        buffer.copy_from_slice(splash);
+
+        // The display shows the buffer once it's initialised, apart from the hidden half
+        self.mode.min_x = 255;
+        self.mode.max_x = 0;
+        self.mode.min_y = 255;
+        self.mode.max_y = 0;
+        self.mode.showing_back = false;
+        self.mark_prev_frame_dirty();
+
+        self.init_impl(AddrMode::Horizontal, Some(splash))
+    }
+
+    /// Bring the display back to a known state after a bus error, for example after an I2C bus
+    /// lockup was cleared.
+    ///
+    /// The display is initialised again, the settings cached by the driver, such as the rotation,
+    /// brightness and inversion, are restored as with
+    /// [`restore_state`](Ssd1306::restore_state), and the whole buffer is flushed. If the display
+    /// doesn't respond at all, it may need a hardware [`reset`](Ssd1306::reset) first.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    /// if display.flush().is_err() {
+    ///     // Clear the bus error with the HAL, then
+    ///     display.recover().unwrap();
+    /// }
+    /// ```
+    pub fn recover(&mut self) -> Result<(), DriverError> {
+        let state = self.save_state();
+
+        // Initialising resets the start line, and the contents of the display memory are unknown
+        self.mode.showing_back = false;
+        self.mark_all_dirty();
+
+        self.init_with_addr_mode(AddrMode::Horizontal)?;
+        self.restore_state(state)?;
+        self.flush()
+    }
+
+    /// Get whether page flipping is enabled.
+    pub fn page_flipping(&self) -> bool {
+        self.mode.page_flipping
+    }
+
+    /// Enable or disable page flipping.
+    ///
+    /// Displays such as 128x32 modules only show half of the 64 rows of the display memory. With
+    /// page flipping enabled, [`flush`](Ssd1306::flush) draws to the half of the display memory
+    /// which isn't visible and then switches the display to it with a single
+    /// [start line](Ssd1306::set_start_line) command, so a partially drawn frame is never
+    /// shown. Each flush sends the area changed in the last two frames, since the hidden half
+    /// last received the frame before the one on screen.
+    ///
+    /// The whole display is redrawn by the next flushes after page flipping is enabled or
+    /// disabled. The start line must not be changed while page flipping is enabled.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the display uses more than half of the
+    /// rows of the display memory, is a [window](crate::size::DisplaySize) of a larger panel, or
+    /// its height or row offset isn't a multiple of 8.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.set_page_flipping(true).unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    ///
+    /// // Draws the frame to the hidden half and then shows it
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_page_flipping(&mut self, enable: bool) -> Result<(), DriverError> {
+        if enable && !self.page_flipping_fits() {
+            return Err(DisplayError::OutOfBoundsError.into());
+        }
+
+        if enable != self.mode.page_flipping {
+            self.mode.page_flipping = enable;
+
+            self.mark_all_dirty();
+        }
+
+        Ok(())
+    }
+
+    /// Set the display rotation, transforming the buffer so the image drawn so far keeps its
+    /// orientation on the rotated display, for example when an orientation sensor reports that
+    /// the device was turned.
+    ///
+    /// [`set_rotation`](Ssd1306::set_rotation) leaves the buffer untouched, so the image is only
+    /// kept when turning the display by 180 degrees. Turning it by 90 degrees swaps the width and
+    /// height of the display, so only the square at the top left of the image is kept and the
+    /// rest of the buffer is cleared. The whole display is redrawn by the next flush.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.set_pixel(10, 20, true);
+    ///
+    /// display
+    ///     .set_rotation_preserving(DisplayRotation::Rotate90)
+    ///     .unwrap();
+    ///
+    /// // The pixel is still drawn 10px from the left and 20px from the top of the display
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_rotation_preserving(
+        &mut self,
+        rotation: DisplayRotation,
+    ) -> Result<(), DriverError> {
+        let portrait = |rotation| {
+            matches!(
+                rotation,
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270
+            )
+        };
+
+        if portrait(self.rotation) != portrait(rotation) {
+            self.transpose_buffer();
+            self.set_rotation(rotation)?;
+            self.mark_all_dirty();
+
+            Ok(())
+        } else {
+            // Both rotations use the same buffer layout, and the display reverses the scan
+            // directions to turn the image
+            self.set_rotation(rotation)
+        }
+    }
+
+    /// Mirror the square at the top left of the buffer along its diagonal and clear the rest of
+    /// the buffer, swapping the X and Y coordinates of every pixel which fits in both a portrait
+    /// and a landscape rotation
+    fn transpose_buffer(&mut self) {
+        let width = usize::from(SIZE::WIDTH);
+        let height = usize::from(SIZE::HEIGHT);
+        let square = width.min(height);
+        let buffer = self.mode.buffer.as_mut();
+
+        // Index and bit of a pixel, in the layout of the display memory
+        let bit = |column: usize, row: usize| (row / 8 * width + column, row % 8);
+
+        for row in 0..height {
+            for column in 0..width {
+                let (idx, shift) = bit(column, row);
+
+                if column >= square || row >= square {
+                    buffer[idx] &= !(1 << shift);
+                } else if column > row {
+                    let (other_idx, other_shift) = bit(row, column);
+                    let value = buffer[idx] >> shift & 1;
+                    let other = buffer[other_idx] >> other_shift & 1;
+
+                    buffer[idx] = buffer[idx] & !(1 << shift) | (other << shift);
+                    buffer[other_idx] =
+                        buffer[other_idx] & !(1 << other_shift) | (value << other_shift);
+                }
+            }
+        }
+    }
+
+    /// Get whether a second frame fits below the display in the display memory. The start line
+    /// moves the whole panel, so a display which is a window of a panel can't page flip. Both
+    /// frames are drawn in pages of 8 rows, so they must start at the first row of a page.
+    fn page_flipping_fits(&self) -> bool {
+        let offset_y = self.offsets().1;
+
+        SIZE::HEIGHT == SIZE::PANEL_HEIGHT
+            && offset_y % 8 == 0
+            && SIZE::HEIGHT % 8 == 0
+            && u16::from(offset_y) + 2 * u16::from(SIZE::HEIGHT) <= SIZE::DRIVER_ROWS.into()
+    }
+
+    /// Wake the display from [`sleep`](Ssd1306::sleep) and send the whole buffer to it, for
+    /// displays which may have lost the contents of their memory while asleep.
+    pub fn wake_and_redraw(&mut self) -> Result<(), DriverError> {
+        self.wake()?;
+
+        self.mark_all_dirty();
+
+        self.flush()
+    }
+
+    /// Get whether colors drawn with `embedded-graphics` are inverted.
+    pub fn invert_colors(&self) -> bool {
+        self.mode.invert_colors
+    }
+
+    /// Invert the colors drawn with `embedded-graphics`, so `BinaryColor::On` is drawn as a dark
+    /// pixel and `BinaryColor::Off` as a lit pixel. This allows a dark-on-light UI to be drawn
+    /// without changing the drawing code, the same as the
+    /// [hardware inversion](Ssd1306::set_invert) but in the buffer.
+    ///
+    /// This only affects what is drawn afterwards. [`set_pixel`](Ssd1306::set_pixel) and
+    /// [`clear_buffer`](Ssd1306::clear_buffer) are not affected.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.set_invert_colors(true);
+    ///
+    /// // Lights up all pixels
+    /// display.clear(BinaryColor::Off).unwrap();
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_invert_colors(&mut self, invert: bool) {
+        self.mode.invert_colors = invert;
+    }
+
+    /// Get how drawn pixels are combined with the buffer.
+    pub fn raster_op(&self) -> RasterOp {
+        self.mode.raster_op
+    }
+
+    /// Choose how pixels drawn with [`set_pixel`](Ssd1306::set_pixel) or `embedded-graphics`
+    /// are combined with the pixels already in the buffer. By default they replace them.
+    ///
+    /// With [`RasterOp::Xor`], drawing the same shape twice restores what was below it, for
+    /// example for a cursor or a selection rectangle. Only the pixels which are drawn are
+    /// combined, so with [`RasterOp::And`] the pixels drawn in `BinaryColor::Off` are cleared.
+    /// Clearing the buffer isn't affected.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_raster_op(RasterOp::Xor);
+    ///
+    /// // Show a cursor
+    /// display.set_pixel(10, 20, true);
+    /// display.flush().unwrap();
+    ///
+    /// // Hide it again, restoring the pixel below it
+    /// display.set_pixel(10, 20, true);
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_raster_op(&mut self, op: RasterOp) {
+        self.mode.raster_op = op;
+    }
+
     /// Clear the underlying framebuffer. You need to call `disp.flush()` for any effect on the screen.
     pub fn clear_buffer(&mut self) {
         self.clear_impl(false);
     }
 
+    /// Invert every pixel in the buffer. You need to call `disp.flush()` for any effect on the
+    /// screen.
+    ///
+    /// Unlike [`set_invert_colors`](Ssd1306::set_invert_colors), this changes the contents of the
+    /// buffer, so later drawing isn't inverted.
+    pub fn invert_all(&mut self) {
+        self.mode
+            .buffer
+            .as_mut()
+            .iter_mut()
+            .for_each(|byte| *byte = !*byte);
+
+        let (width, height) = self.dimensions();
+        self.mark_area_dirty((0, 0), (width - 1, height - 1));
+    }
+
     /// Write out data to a display.
     ///
-    /// This only updates the parts of the display that have changed since the last flush.
-    pub fn flush(&mut self) -> Result<(), DisplayError> {
+    /// This only updates the parts of the display that have changed since the last flush. If
+    /// [page flipping](Ssd1306::set_page_flipping) is enabled, the changes are drawn to the
+    /// hidden half of the display memory, which is then shown.
+    pub fn flush(&mut self) -> Result<(), DriverError> {
+        self.flush_layers(&mut [])
+    }
+
+    /// Write out data to a display, combining the buffer with the visible `layers`, in order.
+    ///
+    /// The buffer itself isn't changed. Besides the parts of the display that have changed since
+    /// the last flush, the parts changed in any of the layers are sent. The same layers should be
+    /// passed to every flush, or the display must be redrawn after adding or removing a layer.
+    ///
+    /// See the [`layer`](crate::layer) module for an example.
+    pub fn flush_layers(&mut self, layers: &mut [&mut Layer<SIZE>]) -> Result<(), DriverError> {
+        self.interface.count_flush();
+        self.with_operation(Operation::Flush, |display| {
+            display.flush_layers_impl(layers)
+        })
+    }
+
+    /// Write out the parts of the buffer and `layers` which have changed since the last flush
+    fn flush_layers_impl(&mut self, layers: &mut [&mut Layer<SIZE>]) -> Result<(), DisplayError> {
+        for layer in layers.iter_mut() {
+            let dirty = layer.take_dirty();
+
+            self.mode.min_x = self.mode.min_x.min(dirty.min_x);
+            self.mode.max_x = self.mode.max_x.max(dirty.max_x);
+            self.mode.min_y = self.mode.min_y.min(dirty.min_y);
+            self.mode.max_y = self.mode.max_y.max(dirty.max_y);
+        }
+
+        // The whole buffer has to be sent to the new place in the display memory
+        if self.offsets() != self.mode.offsets {
+            self.mark_all_dirty();
+            self.mode.offsets = self.offsets();
+        }
+
+        // The offsets may have been changed since page flipping was enabled
+        if self.mode.page_flipping && !self.page_flipping_fits() {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
         // Nothing to do if no pixels have changed since the last update
         if self.mode.max_x < self.mode.min_x || self.mode.max_y < self.mode.min_y {
             return Ok(());
@@ -94,19 +560,37 @@ where
 
         let (width, height) = self.dimensions();
 
+        let (mut min_x, mut max_x, mut min_y, mut max_y) = (
+            self.mode.min_x,
+            self.mode.max_x,
+            self.mode.min_y,
+            self.mode.max_y,
+        );
+
+        if self.mode.page_flipping {
+            // The hidden half is also missing the changes of the frame on screen
+            min_x = min_x.min(self.mode.prev_min_x);
+            max_x = max_x.max(self.mode.prev_max_x);
+            min_y = min_y.min(self.mode.prev_min_y);
+            max_y = max_y.max(self.mode.prev_max_y);
+
+            self.mode.prev_min_x = self.mode.min_x;
+            self.mode.prev_max_x = self.mode.max_x;
+            self.mode.prev_min_y = self.mode.min_y;
+            self.mode.prev_max_y = self.mode.max_y;
+        }
+
         // Determine which bytes need to be sent
-        let disp_min_x = self.mode.min_x;
-        let disp_min_y = self.mode.min_y;
+        let disp_min_x = min_x;
+        let disp_min_y = min_y;
 
         let (disp_max_x, disp_max_y) = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
-                (self.mode.max_x + 1).min(width),
-                (self.mode.max_y | 7).min(height),
-            ),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
-                (self.mode.max_x | 7).min(width),
-                (self.mode.max_y + 1).min(height),
-            ),
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                ((max_x + 1).min(width), (max_y | 7).min(height))
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                ((max_x | 7).min(width), (max_y + 1).min(height))
+            }
         };
 
         self.mode.min_x = 255;
@@ -114,69 +598,536 @@ where
         self.mode.min_y = 255;
         self.mode.max_y = 0;
 
+        let show_back = self.mode.page_flipping && !self.mode.showing_back;
+        let (offset_x, mut offset_y) = self.memory_offsets();
+        if show_back {
+            offset_y += SIZE::HEIGHT;
+        }
+
         // Tell the display to update only the part that has changed
-        let offset_x = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-            }
-        };
 
         match self.rotation {
             DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
                 self.set_draw_area(
-                    (disp_min_x + offset_x, disp_min_y + SIZE::OFFSETY),
-                    (disp_max_x + offset_x, disp_max_y + SIZE::OFFSETY),
+                    (disp_min_x + offset_x, disp_min_y + offset_y),
+                    (disp_max_x + offset_x, disp_max_y + offset_y),
                 )?;
 
-                Self::flush_buffer_chunks(
+                Self::flush_layer_chunks(
                     &mut self.interface,
                     self.mode.buffer.as_mut(),
+                    layers,
                     width as usize,
                     (disp_min_x, disp_min_y),
                     (disp_max_x, disp_max_y),
-                )
+                )?;
             }
             DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
                 self.set_draw_area(
-                    (disp_min_y + offset_x, disp_min_x + SIZE::OFFSETY),
-                    (disp_max_y + offset_x, disp_max_x + SIZE::OFFSETY),
+                    (disp_min_y + offset_x, disp_min_x + offset_y),
+                    (disp_max_y + offset_x, disp_max_x + offset_y),
                 )?;
 
-                Self::flush_buffer_chunks(
+                Self::flush_layer_chunks(
                     &mut self.interface,
                     self.mode.buffer.as_mut(),
+                    layers,
                     height as usize,
                     (disp_min_y, disp_min_x),
                     (disp_max_y, disp_max_x),
-                )
+                )?;
             }
         }
+
+        if show_back != self.mode.showing_back {
+            self.set_start_line(if show_back { SIZE::HEIGHT } else { 0 })?;
+            self.mode.showing_back = show_back;
+        }
+
+        Ok(())
     }
 
-    /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
-    /// coordinates are out of the bounds of the display, this method call is a noop.
-    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
-        let value = value as u8;
-        let rotation = self.rotation;
+    /// Send an area of the buffer, combined with the layers
+    fn flush_layer_chunks(
+        interface: &mut Counting<DI>,
+        buffer: &[u8],
+        layers: &mut [&mut Layer<SIZE>],
+        disp_width: usize,
+        upper_left: (u8, u8),
+        lower_right: (u8, u8),
+    ) -> Result<(), DisplayError> {
+        if layers.iter().all(|layer| !layer.is_visible()) {
+            return Self::flush_buffer_chunks(
+                interface,
+                buffer,
+                disp_width,
+                upper_left,
+                lower_right,
+            );
+        }
 
-        let (idx, bit) = match rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                let idx = ((y as usize) / 8 * SIZE::WIDTH as usize) + (x as usize);
-                let bit = y % 8;
+        let num_pages = ((lower_right.1 - upper_left.1) / 8) as usize + 1;
+        let starting_page = (upper_left.1 / 8) as usize;
 
-                (idx, bit)
+        // The pages are composited into a copy, which is sent in as few transfers as possible
+        Self::send_gathered(
+            interface,
+            disp_width,
+            starting_page..starting_page + num_pages,
+            upper_left.0 as usize..lower_right.0 as usize,
+            |range, chunk| {
+                chunk.copy_from_slice(&buffer[range.clone()]);
+                for layer in layers.iter_mut() {
+                    layer.composite(range.clone(), chunk);
+                }
+            },
+        )
+    }
+
+    /// Get the buffer, in the layout of the display memory.
+    ///
+    /// The buffer holds pages of 8 rows of the display's hardware orientation, from top to
+    /// bottom. Each page has one byte per column, from left to right, where the least significant
+    /// bit is the top row. A set bit is a lit pixel. The rotation of the display is applied by
+    /// the display, so in a rotation by 90 or 270 degrees the columns of the buffer are the rows
+    /// of the rotated display. Use [`buffer_index`](Ssd1306::buffer_index) to find a pixel.
+    pub fn buffer(&self) -> &[u8] {
+        self.mode.buffer.as_ref()
+    }
+
+    /// Get the buffer mutably, in the layout described in [`buffer`](Ssd1306::buffer).
+    ///
+    /// The whole display is sent by the next flush.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Light up the top 8 rows of the first 16 columns
+    /// display.buffer_mut()[..16].fill(0xff);
+    /// assert_eq!(display.get_pixel(15, 7), Some(true));
+    ///
+    /// let (index, bit) = display.buffer_index(3, 10).unwrap();
+    /// display.buffer_mut()[index] |= 1 << bit;
+    /// assert_eq!(display.get_pixel(3, 10), Some(true));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        self.mark_all_dirty();
+
+        self.mode.buffer.as_mut()
+    }
+
+    /// Get the contents of the buffer to keep them while the display is powered down, for example
+    /// in backup RAM during deep sleep, and [restore](Ssd1306::restore_buffer) them later.
+    ///
+    /// The contents are in the layout described in [`buffer`](Ssd1306::buffer), which only
+    /// depends on the size of the display. They hold the pixels of the display's hardware
+    /// orientation, so they must be restored to a display with the same rotation to show the
+    /// same image.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// # let wake_interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.set_pixel(10, 20, true);
+    ///
+    /// // Before deep sleep
+    /// let mut backup = [0; 1024];
+    /// backup.copy_from_slice(display.serialize_buffer());
+    ///
+    /// // After waking up
+    /// let mut display = Ssd1306::new(wake_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.restore_buffer(&backup).unwrap();
+    /// display.flush().unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(10, 20), Some(true));
+    /// ```
+    pub fn serialize_buffer(&self) -> &[u8] {
+        self.buffer()
+    }
+
+    /// Write the contents of the buffer as a plain (`P1`) PBM image, for example to take a
+    /// screenshot over a debug link. Lit pixels are black. The image has the
+    /// [dimensions](Ssd1306::dimensions) of the display in its current rotation.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize96x16, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    /// display.set_pixel(1, 0, true);
+    ///
+    /// let mut screenshot = String::new();
+    /// display.write_pbm(&mut screenshot).unwrap();
+    ///
+    /// assert!(screenshot.starts_with("P1\n16 96\n0100000000000000\n0000000000000000\n"));
+    /// ```
+    pub fn write_pbm(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        let (width, height) = self.dimensions();
+        writeln!(out, "P1\n{} {}", width, height)?;
+
+        for y in 0..u32::from(height) {
+            for x in 0..u32::from(width) {
+                let on = self.get_pixel(x, y) == Some(true);
+                out.write_char(if on { '1' } else { '0' })?;
             }
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                let idx = ((x as usize) / 8 * SIZE::WIDTH as usize) + (y as usize);
-                let bit = x % 8;
+            out.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the contents of the buffer as an XBM image, which is C source code defining
+    /// `<name>_width`, `<name>_height` and `<name>_bits`. Lit pixels are black. The image has the
+    /// [dimensions](Ssd1306::dimensions) of the display in its current rotation.
+    pub fn write_xbm(&self, out: &mut impl fmt::Write, name: &str) -> fmt::Result {
+        let (width, height) = self.dimensions();
+        writeln!(out, "#define {}_width {}", name, width)?;
+        writeln!(out, "#define {}_height {}", name, height)?;
+        writeln!(out, "static unsigned char {}_bits[] = {{", name)?;
+
+        // Rows are padded to whole bytes, with the leftmost pixel in the least significant bit
+        let bytes_per_row = (u32::from(width) + 7) / 8;
+        let bytes = (0..u32::from(height)).flat_map(|y| {
+            (0..bytes_per_row).map(move |byte| {
+                (0..8)
+                    .filter(|bit| self.get_pixel(byte * 8 + bit, y) == Some(true))
+                    .fold(0u8, |value, bit| value | 1 << bit)
+            })
+        });
+
+        let count = bytes_per_row * u32::from(height);
+        for (i, byte) in (1..).zip(bytes) {
+            let separator = match i {
+                i if i == count => "\n",
+                i if i % 12 == 0 => ",\n",
+                _ => ",",
+            };
+            write!(
+                out,
+                "{}0x{:02x}{}",
+                if i % 12 == 1 { "   " } else { " " },
+                byte,
+                separator
+            )?;
+        }
+
+        writeln!(out, "}};")
+    }
+
+    /// Write the contents of the buffer as a PNG image with lit pixels in white, like on the
+    /// display, and every pixel enlarged to `scale` by `scale` pixels, where a scale of 0 is
+    /// treated as 1. The image has the [dimensions](Ssd1306::dimensions) of the display in its
+    /// current rotation.
+    ///
+    /// This is only available with the `std` feature, for example for screenshots in tests on the
+    /// host or on single board computers.
+    #[cfg(feature = "std")]
+    pub fn write_png(&self, out: impl std::io::Write, scale: u32) -> std::io::Result<()> {
+        let (width, height) = self.dimensions();
+        let scale = scale.max(1);
+
+        crate::png::write_png(
+            out,
+            u32::from(width) * scale,
+            u32::from(height) * scale,
+            |x, y| self.get_pixel(x / scale, y / scale) == Some(true),
+        )
+    }
+
+    /// Save the contents of the buffer as a PNG image file, like
+    /// [`write_png`](Ssd1306::write_png).
+    ///
+    /// This is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn save_png(&self, path: impl AsRef<std::path::Path>, scale: u32) -> std::io::Result<()> {
+        let file = std::fs::File::create(path)?;
+
+        self.write_png(std::io::BufWriter::new(file), scale)
+    }
+
+    /// Replace the contents of the buffer with contents from
+    /// [`serialize_buffer`](Ssd1306::serialize_buffer). The whole display is sent by the next
+    /// flush.
+    ///
+    /// Initialising the display clears the buffer, so the buffer must be restored after
+    /// [`init`](DisplayConfig::init). Returns [`DisplayError::OutOfBoundsError`] if `data`
+    /// doesn't have the length of the buffer.
+    pub fn restore_buffer(&mut self, data: &[u8]) -> Result<(), DriverError> {
+        let buffer = self.mode.buffer.as_mut();
+        if data.len() != buffer.len() {
+            return Err(DisplayError::OutOfBoundsError.into());
+        }
+
+        buffer.copy_from_slice(data);
+        self.mark_all_dirty();
+
+        Ok(())
+    }
+
+    /// Get the index of the byte in the [`buffer`](Ssd1306::buffer) and the bit in the byte
+    /// which hold a pixel, for the current rotation, or `None` if the X and Y coordinates are out
+    /// of the bounds of the display.
+    pub fn buffer_index(&self, x: u32, y: u32) -> Option<(usize, u8)> {
+        let (width, height) = self.dimensions();
+        if x >= width.into() || y >= height.into() {
+            return None;
+        }
+
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+
+        Some((idx, bit as u8))
+    }
+
+    /// Copy bytes in the layout of the display memory to a page of the buffer, starting at
+    /// column `x_start`. Each byte is a column of 8 pixels, as described in
+    /// [`buffer`](Ssd1306::buffer).
+    ///
+    /// The page and columns are those of the display's hardware orientation. The bytes replace
+    /// the contents of the buffer, regardless of the [raster operation](Ssd1306::set_raster_op).
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the bytes don't fit in the page.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // An 8x8 checkerboard on rows 16 to 23
+    /// let pattern = [0xaa, 0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa, 0x55];
+    /// display.set_page_bytes(2, 40, &pattern).unwrap();
+    /// assert_eq!(display.get_pixel(40, 17), Some(true));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_page_bytes(
+        &mut self,
+        page: u8,
+        x_start: u8,
+        data: &[u8],
+    ) -> Result<(), DriverError> {
+        let width = usize::from(SIZE::WIDTH);
+        let start = usize::from(x_start);
+        let end = start + data.len();
+
+        if page >= (SIZE::HEIGHT + 7) / 8 || end > width {
+            return Err(DisplayError::OutOfBoundsError.into());
+        }
+
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let offset = usize::from(page) * width;
+        self.mode.buffer.as_mut()[offset + start..offset + end].copy_from_slice(data);
+
+        self.mark_page_dirty(page, x_start, (end - 1) as u8);
+
+        Ok(())
+    }
+
+    /// Draw a bitmap in the layout of the display memory with its top left corner at column `x`
+    /// and row `y` of the buffer.
+    ///
+    /// `data` holds pages of `width` bytes, from top to bottom, where each byte is a column of
+    /// 8 pixels with the least significant bit at the top, as emitted by most OLED image tools.
+    /// If `y` is a multiple of 8, the pages are copied to the buffer directly, otherwise each
+    /// byte is split across two pages of the buffer.
+    ///
+    /// The coordinates are those of the display's hardware orientation. The bitmap replaces the
+    /// contents of the buffer regardless of the [raster operation](Ssd1306::set_raster_op), and
+    /// the parts outside of the display are clipped.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // A 4x16 sprite, two pages of 4 columns
+    /// let sprite = [0xff, 0x81, 0x81, 0xff, 0xff, 0x81, 0x81, 0xff];
+    ///
+    /// display.draw_packed_bitmap(10, 8, 4, &sprite);
+    /// display.draw_packed_bitmap(20, 13, 4, &sprite);
+    /// assert_eq!(display.get_pixel(21, 14), Some(false));
+    /// assert_eq!(display.get_pixel(21, 13), Some(true));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn draw_packed_bitmap(&mut self, x: u8, y: u8, width: u8, data: &[u8]) {
+        self.draw_bitmap_impl(x, y, width, data, None);
+    }
+
+    /// Draw a bitmap like [`draw_packed_bitmap`](Ssd1306::draw_packed_bitmap), but only change
+    /// the pixels of the buffer whose bit is set in `mask`, so sprites which aren't rectangular
+    /// can be drawn over a background.
+    ///
+    /// `mask` has the same layout as `data`. Pixels without a byte in `mask` are transparent.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Turn on every pixel of the background
+    /// display.invert_all();
+    ///
+    /// // A black 3x8 arrow pointing right, which leaves the background around it alone
+    /// let sprite = [0x00, 0x00, 0x00];
+    /// let mask = [0xff, 0x7e, 0x3c];
+    ///
+    /// display.draw_masked_bitmap(10, 4, 3, &sprite, &mask);
+    /// assert_eq!(display.get_pixel(10, 4), Some(false));
+    /// assert_eq!(display.get_pixel(11, 4), Some(true));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn draw_masked_bitmap(&mut self, x: u8, y: u8, width: u8, data: &[u8], mask: &[u8]) {
+        self.draw_bitmap_impl(x, y, width, data, Some(mask));
+    }
+
+    fn draw_bitmap_impl(&mut self, x: u8, y: u8, width: u8, data: &[u8], mask: Option<&[u8]>) {
+        if width == 0 || x >= SIZE::WIDTH || y >= SIZE::HEIGHT {
+            return;
+        }
+
+        let buffer_width = usize::from(SIZE::WIDTH);
+        let pages = (SIZE::HEIGHT + 7) / 8;
+        let shift = y % 8;
+        // Columns of the bitmap which are on the display
+        let columns = usize::from(width.min(SIZE::WIDTH - x));
+        let last_column = x + columns as u8 - 1;
+
+        for (row, bytes) in data.chunks(width.into()).enumerate() {
+            let bytes = &bytes[..columns.min(bytes.len())];
+            let page = usize::from(y / 8) + row;
+
+            if page >= pages.into() {
+                break;
+            }
+
+            let offset = page * buffer_width + usize::from(x);
+            let buffer = self.mode.buffer.as_mut();
+            let mask_byte = |column: usize| {
+                mask.map_or(0xff, |mask| {
+                    mask.get(row * usize::from(width) + column)
+                        .copied()
+                        .unwrap_or(0)
+                })
+            };
+
+            if shift == 0 && mask.is_none() {
+                buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+            } else {
+                // The top of the byte goes to this page, the bottom to the next one
+                for (column, (dst, src)) in buffer[offset..].iter_mut().zip(bytes).enumerate() {
+                    let mask = mask_byte(column) << shift;
+                    *dst = *dst & !mask | (src << shift) & mask;
+                }
+
+                if shift != 0 && page + 1 < pages.into() {
+                    let offset = offset + buffer_width;
 
-                (idx, bit)
+                    for (column, (dst, src)) in buffer[offset..].iter_mut().zip(bytes).enumerate() {
+                        let mask = mask_byte(column) >> (8 - shift);
+                        *dst = *dst & !mask | (src >> (8 - shift)) & mask;
+                    }
+                }
             }
+
+            self.mark_page_dirty(page as u8, x, last_column);
+            if shift != 0 && page + 1 < pages.into() {
+                self.mark_page_dirty(page as u8 + 1, x, last_column);
+            }
+        }
+    }
+
+    /// Mark the columns `first..=last` of a page of the buffer as changed
+    fn mark_page_dirty(&mut self, page: u8, first: u8, last: u8) {
+        let (width, height) = self.dimensions();
+        let rows = (page * 8, (page * 8 + 7).min(SIZE::HEIGHT - 1));
+
+        // The columns of the buffer are the rows of a display rotated by 90 or 270 degrees
+        let ((min_x, max_x), (min_y, max_y)) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => ((first, last), rows),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (rows, (first, last)),
         };
 
+        self.mark_area_dirty(
+            (min_x, min_y),
+            (max_x.min(width - 1), max_y.min(height - 1)),
+        );
+    }
+
+    /// Mark the area from `top_left` to `bottom_right`, inclusive, as changed
+    fn mark_area_dirty(&mut self, top_left: (u8, u8), bottom_right: (u8, u8)) {
+        self.mode.min_x = self.mode.min_x.min(top_left.0);
+        self.mode.max_x = self.mode.max_x.max(bottom_right.0);
+        self.mode.min_y = self.mode.min_y.min(top_left.1);
+        self.mode.max_y = self.mode.max_y.max(bottom_right.1);
+    }
+
+    /// Get whether a pixel is on in the buffer, or `None` if the X and Y coordinates are out of
+    /// the bounds of the display.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 100, true);
+    ///
+    /// assert_eq!(display.get_pixel(10, 100), Some(true));
+    /// assert_eq!(display.get_pixel(11, 100), Some(false));
+    /// assert_eq!(display.get_pixel(100, 10), None);
+    /// ```
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<bool> {
+        let (idx, bit) = self.buffer_index(x, y)?;
+
+        self.buffer().get(idx).map(|byte| byte & (1 << bit) != 0)
+    }
+
+    /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
+    /// coordinates are out of the bounds of the display, this method call is a noop.
+    ///
+    /// The pixel is combined with the buffer using the [raster operation](Ssd1306::set_raster_op).
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        let value = value as u8;
+        let op = self.mode.raster_op;
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+
         if let Some(byte) = self.mode.buffer.as_mut().get_mut(idx) {
             // Keep track of max and min values
             self.mode.min_x = self.mode.min_x.min(x as u8);
@@ -185,24 +1136,454 @@ where
             self.mode.min_y = self.mode.min_y.min(y as u8);
             self.mode.max_y = self.mode.max_y.max(y as u8);
 
+            let value = op.apply(*byte >> bit & 1, value);
+
             // Set pixel value in byte
             // Ref this comment https://stackoverflow.com/questions/47981/how-do-you-set-clear-and-toggle-a-single-bit#comment46654671_47990
             *byte = *byte & !(1 << bit) | (value << bit);
         }
     }
+
+    /// Turn several pixels on or off. Pixels out of the bounds of the display are skipped.
+    ///
+    /// This is faster than calling [`set_pixel`](Ssd1306::set_pixel) for every pixel, since the
+    /// layout of the buffer for the rotation is chosen once and the changed area is tracked once
+    /// at the end.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// // A diagonal line
+    /// display.set_pixels((0..64).map(|i| (i * 2, i, true)));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn set_pixels(&mut self, pixels: impl IntoIterator<Item = (u32, u32, bool)>) {
+        let (width, height) = self.dimensions();
+        let (width, height) = (u32::from(width), u32::from(height));
+        let op = self.mode.raster_op;
+        let buffer_width = usize::from(SIZE::WIDTH);
+        let buffer = self.mode.buffer.as_mut();
+
+        // Track the changed area in locals and store it once at the end
+        let (mut min_x, mut max_x) = (self.mode.min_x, self.mode.max_x);
+        let (mut min_y, mut max_y) = (self.mode.min_y, self.mode.max_y);
+
+        let mut plot = |x: u32, y: u32, column: u32, row: u32, value: bool| {
+            let byte = &mut buffer[(row / 8) as usize * buffer_width + column as usize];
+            let bit = row % 8;
+            let value = op.apply(*byte >> bit & 1, value as u8);
+
+            *byte = *byte & !(1 << bit) | (value << bit);
+
+            min_x = min_x.min(x as u8);
+            max_x = max_x.max(x as u8);
+            min_y = min_y.min(y as u8);
+            max_y = max_y.max(y as u8);
+        };
+
+        let pixels = pixels
+            .into_iter()
+            .filter(|(x, y, _value)| *x < width && *y < height);
+
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                pixels.for_each(|(x, y, value)| plot(x, y, x, y, value));
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                pixels.for_each(|(x, y, value)| plot(x, y, y, x, value));
+            }
+        }
+
+        self.mode.min_x = min_x;
+        self.mode.max_x = max_x;
+        self.mode.min_y = min_y;
+        self.mode.max_y = max_y;
+    }
+
+    /// Turn a pixel on or off without checking the coordinates, for hot loops which already
+    /// clipped them to the [dimensions](Ssd1306::dimensions) of the display.
+    ///
+    /// The coordinates are checked by a debug assertion. In release builds, coordinates out of
+    /// the bounds of the display change another pixel or panic.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// let (width, height) = display.dimensions();
+    /// for y in 0..u32::from(height) {
+    ///     for x in 0..u32::from(width) {
+    ///         display.set_pixel_unchecked(x, y, (x ^ y) & 1 == 0);
+    ///     }
+    /// }
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    #[inline]
+    pub fn set_pixel_unchecked(&mut self, x: u32, y: u32, value: bool) {
+        debug_assert!(
+            {
+                let (width, height) = self.dimensions();
+                x < width.into() && y < height.into()
+            },
+            "pixel out of bounds"
+        );
+
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+        let byte = &mut self.mode.buffer.as_mut()[idx];
+        let value = self.mode.raster_op.apply(*byte >> bit & 1, value as u8);
+
+        *byte = *byte & !(1 << bit) | (value << bit);
+
+        self.mode.min_x = self.mode.min_x.min(x as u8);
+        self.mode.max_x = self.mode.max_x.max(x as u8);
+        self.mode.min_y = self.mode.min_y.min(y as u8);
+        self.mode.max_y = self.mode.max_y.max(y as u8);
+    }
+
+    /// Move the contents of the buffer up by a number of pixels, clearing the rows at the bottom
+    /// of the display. The whole display is redrawn by the next call to `disp.flush()`.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    /// display.scroll_up(3);
+    ///
+    /// assert_eq!(display.get_pixel(10, 17), Some(true));
+    /// assert_eq!(display.get_pixel(10, 20), Some(false));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn scroll_up(&mut self, pixels: u32) {
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => self.shift_rows(pixels),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => self.shift_columns(pixels),
+        }
+
+        self.mark_all_dirty();
+    }
+
+    /// Move the contents of the buffer left by a number of pixels, clearing the columns at the
+    /// right of the display. The whole display is redrawn by the next call to `disp.flush()`.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    /// display.scroll_left(10);
+    ///
+    /// assert_eq!(display.get_pixel(0, 20), Some(true));
+    /// assert_eq!(display.get_pixel(10, 20), Some(false));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn scroll_left(&mut self, pixels: u32) {
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => self.shift_columns(pixels),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => self.shift_rows(pixels),
+        }
+
+        self.mark_all_dirty();
+    }
+
+    /// Move every row of the buffer towards row 0 by `rows`, in the layout of the display memory
+    fn shift_rows(&mut self, rows: u32) {
+        let width = usize::from(SIZE::WIDTH);
+        let pages = usize::from(SIZE::HEIGHT + 7) / 8;
+        let skip = (rows / 8) as usize;
+        let shift = rows % 8;
+        let buffer = self.mode.buffer.as_mut();
+
+        for page in 0..pages {
+            for column in 0..width {
+                // Bytes past the bottom of the buffer are empty
+                let byte = |page: usize| {
+                    if page < pages {
+                        buffer[page * width + column]
+                    } else {
+                        0
+                    }
+                };
+
+                let value = if skip >= pages {
+                    0
+                } else if shift == 0 {
+                    byte(page + skip)
+                } else {
+                    byte(page + skip) >> shift | byte(page + skip + 1) << (8 - shift)
+                };
+
+                buffer[page * width + column] = value;
+            }
+        }
+    }
+
+    /// Move every column of the buffer towards column 0 by `columns`, in the layout of the
+    /// display memory
+    fn shift_columns(&mut self, columns: u32) {
+        let width = usize::from(SIZE::WIDTH);
+        let columns = (columns as usize).min(width);
+
+        for page in self.mode.buffer.as_mut().chunks_mut(width) {
+            page.copy_within(columns.., 0);
+            page[width - columns..].fill(0);
+        }
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand + ReadMemory,
+    SIZE: DisplaySize,
+{
+    /// Read back the display memory shown on the display and compare it with the buffer, to
+    /// detect transfers which were corrupted, for example by noise on long cables.
+    ///
+    /// Returns the first byte which doesn't match, if any. The whole display is then sent again
+    /// by the next flush. Changes to the buffer since the last flush and
+    /// [layers](crate::layer) are reported as mismatches, so this should be called directly after
+    /// [`flush`](Self::flush), or by using [`flush_verified`](Self::flush_verified).
+    ///
+    /// This needs an interface which can read the display memory, which is only possible over the
+    /// parallel interfaces of the display. The display memory is read in pages of 8 rows, so
+    /// [`DisplayError::OutOfBoundsError`] is returned if the row offset of the display isn't a
+    /// multiple of 8.
+    pub fn verify(&mut self) -> Result<Option<Mismatch>, DriverError> {
+        let (width, height) = self.dimensions();
+        let (columns, pages) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (width, height / 8),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (height, width / 8),
+        };
+
+        let (offset_x, mut offset_y) = self.memory_offsets();
+        if offset_y % 8 != 0 {
+            return Err(DisplayError::OutOfBoundsError.into());
+        }
+
+        if self.mode.showing_back {
+            offset_y += SIZE::HEIGHT;
+        }
+
+        let mut read = [0; 128];
+        let read = &mut read[..usize::from(columns)];
+
+        for page in 0..pages {
+            let row = offset_y + page * 8;
+            if self.addr_mode == AddrMode::Page {
+                self.set_column(offset_x)?;
+                self.set_row(row)?;
+            } else {
+                self.set_draw_area((offset_x, row), (offset_x + columns, row + 8))?;
+            }
+
+            self.interface.iface.read_memory(read)?;
+
+            let start = usize::from(page) * read.len();
+            let expected = &self.mode.buffer.as_ref()[start..start + read.len()];
+
+            if let Some(column) = read.iter().zip(expected).position(|(a, b)| a != b) {
+                self.mark_all_dirty();
+
+                return Ok(Some(Mismatch {
+                    page: (offset_y / 8) + page,
+                    column: offset_x + column as u8,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Write out data to the display like [`flush`](Self::flush), and [`verify`](Self::verify)
+    /// that the display memory matches the buffer.
+    pub fn flush_verified(&mut self) -> Result<Option<Mismatch>, DriverError> {
+        self.flush()?;
+        self.verify()
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand + ReadStatus,
+    SIZE: DisplaySize,
+{
+    /// Detect whether the display was reset, for example by a brownout of its supply while the
+    /// microcontroller kept running, and [`recover`](Self::recover) it if it was.
+    ///
+    /// A display which was reset is switched off, so this reads the [status](crate::status) of
+    /// the display, and recovers it if it's off although it was switched on by the driver.
+    /// Returns whether the display was recovered. Call this periodically, or before flushing.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::I2cStub;
+    /// # let i2c = I2cStub;
+    /// use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+    ///
+    /// let interface = I2CDisplayInterface::new_readable(i2c);
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// loop {
+    ///     display.set_pixel(10, 20, true);
+    ///
+    ///     if !display.recover_if_reset().unwrap() {
+    ///         display.flush().unwrap();
+    ///     }
+    /// #   break;
+    /// }
+    /// ```
+    pub fn recover_if_reset(&mut self) -> Result<bool, DriverError> {
+        if !self.display_on || self.read_status()?.is_display_on() {
+            return Ok(false);
+        }
+
+        self.recover()?;
+
+        Ok(true)
+    }
 }
 
 #[cfg(feature = "graphics")]
 use embedded_graphics_core::{
     draw_target::DrawTarget,
     geometry::Size,
-    geometry::{Dimensions, OriginDimensions},
+    geometry::{Dimensions, OriginDimensions, Point},
+    image::{GetPixel, ImageDrawable},
     pixelcolor::BinaryColor,
+    primitives::Rectangle,
     Pixel,
 };
 
 use super::DisplayConfig;
 
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Turn off all pixels in an area of the buffer, for example to erase a widget before
+    /// drawing it again. You need to call `disp.flush()` for any effect on the screen.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.clear_area(&Rectangle::new(Point::new(0, 0), Size::new(64, 12)));
+    /// display.flush().unwrap();
+    /// ```
+    pub fn clear_area(&mut self, area: &Rectangle) {
+        self.fill_area(area, false);
+    }
+
+    /// Turn all pixels in an area of the buffer on or off. The parts of the area outside of the
+    /// display are ignored.
+    ///
+    /// Like [`clear_buffer`](Ssd1306::clear_buffer), this isn't affected by the
+    /// [raster operation](Ssd1306::set_raster_op) or the
+    /// [inverted colors](Ssd1306::set_invert_colors).
+    pub fn fill_area(&mut self, area: &Rectangle, value: bool) {
+        self.fill_area_impl(area, value, RasterOp::Replace);
+    }
+
+    /// Invert all pixels in an area of the buffer, for example to highlight the selected row of a
+    /// menu. The parts of the area outside of the display are ignored.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    /// display.invert_area(&Rectangle::new(Point::new(0, 16), Size::new(128, 10)));
+    ///
+    /// assert_eq!(display.get_pixel(10, 20), Some(false));
+    /// assert_eq!(display.get_pixel(11, 20), Some(true));
+    /// assert_eq!(display.get_pixel(11, 26), Some(false));
+    ///
+    /// display.flush().unwrap();
+    /// ```
+    pub fn invert_area(&mut self, area: &Rectangle) {
+        self.fill_area_impl(area, true, RasterOp::Xor);
+    }
+
+    fn fill_area_impl(&mut self, area: &Rectangle, value: bool, op: RasterOp) {
+        let drawn = area.intersection(&self.bounding_box());
+        let bottom_right = match drawn.bottom_right() {
+            Some(bottom_right) => bottom_right,
+            None => return,
+        };
+
+        let value = if value { 0xff } else { 0 };
+
+        let x = drawn.top_left.x as usize..=bottom_right.x as usize;
+        let y = drawn.top_left.y as usize..=bottom_right.y as usize;
+
+        // The columns of the buffer are the rows of a display rotated by 90 or 270 degrees
+        let (columns, rows) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x),
+        };
+
+        let width = usize::from(SIZE::WIDTH);
+        let buffer = self.mode.buffer.as_mut();
+
+        for page in rows.start() / 8..=rows.end() / 8 {
+            // Bits of the rows of the area in this page
+            let first = rows.start().max(&(page * 8)) - page * 8;
+            let last = rows.end().min(&(page * 8 + 7)) - page * 8;
+            let mask = (0xff << first) & (0xff >> (7 - last));
+
+            let offset = page * width;
+            for byte in &mut buffer[offset + columns.start()..=offset + columns.end()] {
+                *byte = *byte & !mask | op.apply(*byte, value) & mask;
+            }
+        }
+
+        self.mark_area_dirty(
+            (drawn.top_left.x as u8, drawn.top_left.y as u8),
+            (bottom_right.x as u8, bottom_right.y as u8),
+        );
+    }
+}
+
 #[cfg(feature = "graphics")]
 impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
 where
@@ -216,20 +1597,96 @@ where
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        let bb = self.bounding_box();
+        let invert = self.mode.invert_colors;
 
-        pixels
-            .into_iter()
-            .filter(|Pixel(pos, _color)| bb.contains(*pos))
-            .for_each(|Pixel(pos, color)| {
-                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
-            });
+        // Negative coordinates wrap around to large unsigned ones, which are clipped
+        self.set_pixels(
+            pixels
+                .into_iter()
+                .map(|Pixel(pos, color)| (pos.x as u32, pos.y as u32, color.is_on() != invert)),
+        );
+
+        Ok(())
+    }
+
+    /// Images such as `ImageRaw` are drawn with this method. The colors are written to the
+    /// buffer directly, a row at a time, and the changed area is tracked once instead of for
+    /// every pixel.
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let drawn = area.intersection(&self.bounding_box());
+        let bottom_right = match drawn.bottom_right() {
+            Some(bottom_right) => bottom_right,
+            None => return Ok(()),
+        };
+
+        let invert = self.mode.invert_colors;
+        let op = self.mode.raster_op;
+        let rotation = self.rotation;
+        let width = usize::from(SIZE::WIDTH);
+        let buffer = self.mode.buffer.as_mut();
+
+        let write = |byte: &mut u8, bit: usize, color: BinaryColor| {
+            let value = op.apply(*byte >> bit & 1, (color.is_on() != invert) as u8);
+
+            *byte = *byte & !(1 << bit) | (value << bit);
+        };
+
+        // Colors of each row of the area which are left of the display
+        let skip = (drawn.top_left.x - area.top_left.x) as usize;
+        let mut colors = colors.into_iter();
+
+        for y in area.rows().take_while(|y| *y <= bottom_right.y) {
+            let mut row = colors.by_ref().take(area.size.width as usize);
+
+            if y >= drawn.top_left.y {
+                let y = y as usize;
+                let pixels = (drawn.top_left.x as usize..)
+                    .zip(row.by_ref().skip(skip).take(drawn.size.width as usize));
+
+                match rotation {
+                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                        // The whole row is in one page of the buffer
+                        let offset = y / 8 * width;
+
+                        for (x, color) in pixels {
+                            write(&mut buffer[offset + x], y % 8, color);
+                        }
+                    }
+                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                        // The row is a column of the buffer
+                        for (x, color) in pixels {
+                            write(&mut buffer[x / 8 * width + y], x % 8, color);
+                        }
+                    }
+                }
+            }
+
+            // Skip the colors right of the display
+            row.for_each(drop);
+        }
+
+        self.mark_area_dirty(
+            (drawn.top_left.x as u8, drawn.top_left.y as u8),
+            (bottom_right.x as u8, bottom_right.y as u8),
+        );
+
+        Ok(())
+    }
+
+    /// Whole bytes of the buffer are filled at once, masking the rows at the top and bottom edges
+    /// of the area.
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let on = color.is_on() != self.mode.invert_colors;
+        self.fill_area_impl(area, on, self.mode.raster_op);
 
         Ok(())
     }
 
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
-        self.clear_impl(color.is_on());
+        self.clear_impl(color.is_on() != self.mode.invert_colors);
         Ok(())
     }
 }
@@ -246,3 +1703,126 @@ where
         Size::new(w.into(), h.into())
     }
 }
+
+/// Pixels are read back in the color they were drawn with, so a lit pixel is `BinaryColor::Off`
+/// while [inverting the colors](Ssd1306::set_invert_colors).
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+///
+/// Pixel(Point::new(10, 20), BinaryColor::On)
+///     .draw(&mut display)
+///     .unwrap();
+///
+/// assert_eq!(display.pixel(Point::new(10, 20)), Some(BinaryColor::On));
+/// assert_eq!(display.pixel(Point::new(11, 20)), Some(BinaryColor::Off));
+/// assert_eq!(display.pixel(Point::new(-1, 20)), None);
+/// ```
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> GetPixel for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if p.x < 0 || p.y < 0 {
+            return None;
+        }
+
+        self.get_pixel(p.x as u32, p.y as u32)
+            .map(|on| BinaryColor::from(on != self.mode.invert_colors))
+    }
+}
+
+/// The contents of the buffer can be drawn to another [`DrawTarget`], for example to show them
+/// in a simulator or to copy them to another display. Lit pixels are drawn as
+/// `BinaryColor::On`.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// # let other_interface = StubInterface;
+/// use embedded_graphics::{image::Image, prelude::*};
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// display.set_pixel(10, 20, true);
+///
+/// let mut other = Ssd1306::new(other_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// other.init().unwrap();
+///
+/// Image::new(&display, Point::zero()).draw(&mut other).unwrap();
+/// assert_eq!(other.get_pixel(10, 20), Some(true));
+/// ```
+///
+/// Drawing an area which extends past the display with
+/// [`draw_sub_image`](ImageDrawable::draw_sub_image) keeps the pixels on the display at their
+/// place within the area:
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// # let other_interface = StubInterface;
+/// use embedded_graphics::{image::ImageDrawable, prelude::*, primitives::Rectangle};
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// display.set_pixel(1, 1, true);
+///
+/// let mut other = Ssd1306::new(other_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// other.init().unwrap();
+///
+/// // The area starts 2 columns left of and 3 rows above the display
+/// let area = Rectangle::new(Point::new(-2, -3), Size::new(8, 8));
+/// display.draw_sub_image(&mut other, &area).unwrap();
+/// assert_eq!(other.get_pixel(3, 4), Some(true));
+/// assert_eq!(other.get_pixel(1, 1), Some(false));
+/// ```
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> ImageDrawable for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_sub_image(target, &self.bounding_box())
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        // Only the part of the area on the display is drawn, at its place within the area
+        let visible = area.intersection(&self.bounding_box());
+
+        let points = visible
+            .rows()
+            .flat_map(|y| visible.columns().map(move |x| Point::new(x, y)));
+
+        target.draw_iter(points.map(|pos| {
+            let on = self.get_pixel(pos.x as u32, pos.y as u32) == Some(true);
+
+            Pixel(pos - area.top_left, BinaryColor::from(on))
+        }))
+    }
+}
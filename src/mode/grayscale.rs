@@ -0,0 +1,242 @@
+//! Grayscale graphics mode.
+
+use crate::{
+    command::AddrMode,
+    error::{DriverError, Operation},
+    mode::buffered_graphics::pixel_index,
+    rotation::DisplayRotation,
+    size::{DisplaySize, NewZeroed},
+    trace, Ssd1306,
+};
+use display_interface::{DataFormat::U8, DisplayError, WriteOnlyDataCommand};
+
+use super::DisplayConfig;
+
+/// Number of frames sent to the display for every frame of the buffer
+const SUBFRAMES: u8 = 3;
+
+/// Grayscale graphics mode. **Experimental.**
+///
+/// This mode keeps two pixel buffers in system memory, for 4 levels of gray from `0` (off) to `3`
+/// (fully on). The display can only turn pixels on or off, so every call to
+/// [`flush`](Ssd1306::flush) sends the next of 3 frames, where pixels at level `1` are lit in one
+/// frame, pixels at level `2` in two frames and pixels at level `3` in all of them. Persistence
+/// of vision blends the frames into shades of gray.
+///
+/// `flush` has to be called at a steady rate, usually at least every 5 ms, for example from a
+/// timer. Every call sends the whole display, so a fast interface such as SPI is needed. Beating
+/// between the flushes and the refresh rate of the display can show up as flicker, which can be
+/// reduced by changing the [display clock](Ssd1306::set_display_clock).
+///
+/// The buffer is drawn to by [`set_pixel`](Ssd1306::set_pixel) commands or
+/// [`embedded-graphics`](https://docs.rs/embedded-graphics) commands with `Gray2` colors.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_grayscale_mode();
+/// display.init().unwrap();
+///
+/// for x in 0..128 {
+///     display.set_pixel(x, 10, (x / 32) as u8);
+/// }
+///
+/// loop {
+///     display.flush().unwrap();
+/// #   break;
+///     // Wait for the next tick of a timer
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GrayscaleMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Low bits of the level of each pixel, in the layout of the display memory
+    low: SIZE::Buffer,
+    /// High bits of the level of each pixel, in the layout of the display memory
+    high: SIZE::Buffer,
+    /// Frame sent by the next flush
+    subframe: u8,
+}
+
+impl<SIZE> GrayscaleMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Create a new grayscale graphics mode instance.
+    pub(crate) fn new() -> Self {
+        Self {
+            low: NewZeroed::new_zeroed(),
+            high: NewZeroed::new_zeroed(),
+            subframe: 0,
+        }
+    }
+}
+
+impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Error = DriverError;
+
+    /// Set the display rotation
+    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DriverError> {
+        self.set_rotation(rot)
+    }
+
+    /// Initialise and clear the display in grayscale graphics mode.
+    fn init(&mut self) -> Result<(), DriverError> {
+        self.clear_buffer();
+        self.init_with_addr_mode(AddrMode::Horizontal)
+    }
+
+    /// Attach to an initialised display in grayscale graphics mode. The display is redrawn by
+    /// every flush, so its contents are replaced by the first one.
+    fn attach(&mut self) -> Result<(), DriverError> {
+        self.attach_with_addr_mode(AddrMode::Horizontal)
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Clear the underlying framebuffers. The display is cleared by the next flushes.
+    pub fn clear_buffer(&mut self) {
+        self.mode.low.as_mut().fill(0);
+        self.mode.high.as_mut().fill(0);
+    }
+
+    /// Send the next frame to the display.
+    ///
+    /// This has to be called at a steady rate for the levels of gray to show, see
+    /// [`GrayscaleMode`].
+    pub fn flush(&mut self) -> Result<(), DriverError> {
+        self.interface.count_flush();
+        self.with_operation(Operation::Flush, Self::flush_impl)
+    }
+
+    /// Write out the frame, see [`flush`](Self::flush)
+    fn flush_impl(&mut self) -> Result<(), DisplayError> {
+        let (offset_x, offset_y) = self.memory_offsets();
+        self.set_draw_area(
+            (offset_x, offset_y),
+            (offset_x + SIZE::WIDTH, offset_y + SIZE::HEIGHT),
+        )?;
+
+        let subframe = self.mode.subframe;
+        self.mode.subframe = (subframe + 1) % SUBFRAMES;
+
+        let width = usize::from(SIZE::WIDTH);
+        let mut chunk = [0; 128];
+
+        for (low, high) in self
+            .mode
+            .low
+            .as_ref()
+            .chunks(width)
+            .zip(self.mode.high.as_ref().chunks(width))
+        {
+            let chunk = &mut chunk[..low.len()];
+
+            // Pixels are lit in as many frames as their level
+            for (byte, (low, high)) in chunk.iter_mut().zip(low.iter().zip(high)) {
+                *byte = match subframe {
+                    0 => low | high,
+                    1 => *high,
+                    _ => low & high,
+                };
+            }
+
+            trace::data(chunk);
+            self.interface.send_data(U8(chunk))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the level of gray of a pixel from `0` to `3`, or `None` if the X and Y coordinates are
+    /// out of the bounds of the display.
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<u8> {
+        let (width, height) = self.dimensions();
+        if x >= width.into() || y >= height.into() {
+            return None;
+        }
+
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+        let low = self.mode.low.as_ref()[idx] >> bit & 1;
+        let high = self.mode.high.as_ref()[idx] >> bit & 1;
+
+        Some(high << 1 | low)
+    }
+
+    /// Set the level of gray of a pixel from `0` (off) to `3` (fully on). Levels above `3` are
+    /// treated as `3`. If the X and Y coordinates are out of the bounds of the display, this
+    /// method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, level: u8) {
+        let (width, height) = self.dimensions();
+        if x >= width.into() || y >= height.into() {
+            return;
+        }
+
+        let level = level.min(3);
+        let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+
+        let low = &mut self.mode.low.as_mut()[idx];
+        *low = *low & !(1 << bit) | (level & 1) << bit;
+
+        let high = &mut self.mode.high.as_mut()[idx];
+        *high = *high & !(1 << bit) | (level >> 1) << bit;
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::{Gray2, GrayColor},
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = Gray2;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| pos.x >= 0 && pos.y >= 0)
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.luma());
+            });
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> OriginDimensions for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(w.into(), h.into())
+    }
+}
@@ -1,11 +1,32 @@
 //! Display modes.
+//!
+//! # Memory use
+//!
+//! The display memory of the SSD1306 can only be read over the parallel interfaces. Over I2C
+//! and SPI, only the [status byte](crate::status) can be read, so pixels can't be changed with
+//! a read-modify-write of the display memory, and modes which draw single pixels have to keep a
+//! copy of the display contents in a buffer. On devices which can't spare the memory:
+//!
+//! - [`BasicMode`] has no buffer, and draws whole frames or areas from data in flash, for
+//!   example with [`draw_frame`](crate::Ssd1306::draw_frame) or
+//!   [`draw_rle_frame`](crate::Ssd1306::draw_rle_frame).
+//! - [`TerminalMode`] has no buffer, and draws characters directly to the display.
+//! - [`PixelDoubledMode`] uses a 256 byte buffer, drawing every pixel as a 2x2 block.
+//! - A custom [`DisplaySize`] can buffer only a part of the display, such as a status bar.
 
 mod buffered_graphics;
+mod grayscale;
+mod pixel_doubled;
 mod terminal;
 
-use crate::{command::AddrMode, rotation::DisplayRotation, size::DisplaySize, Ssd1306};
+use crate::{
+    command::AddrMode, error::DriverError, pbm::frame_len, rle, rotation::DisplayRotation,
+    size::DisplaySize, Ssd1306,
+};
 pub use buffered_graphics::*;
 use display_interface::{DisplayError, WriteOnlyDataCommand};
+pub use grayscale::*;
+pub use pixel_doubled::*;
 pub use terminal::*;
 
 /// Common functions to all display modes.
@@ -18,6 +39,10 @@ pub trait DisplayConfig {
 
     /// Initialise and configure the display for the given mode.
     fn init(&mut self) -> Result<(), Self::Error>;
+
+    /// Set up the driver for the given mode without initialising the display, which must already
+    /// have been initialised, for example by a bootloader. The contents of the display are kept.
+    fn attach(&mut self) -> Result<(), Self::Error>;
 }
 
 /// A mode with no additional functionality beyond that provided by the base [`Ssd1306`] struct.
@@ -30,7 +55,7 @@ where
     SIZE: DisplaySize,
 {
     /// Clear the display.
-    pub fn clear(&mut self) -> Result<(), DisplayError> {
+    pub fn clear(&mut self) -> Result<(), DriverError> {
         let old_addr_mode = self.addr_mode;
         if old_addr_mode != AddrMode::Horizontal {
             self.set_addr_mode(AddrMode::Horizontal)?;
@@ -58,6 +83,169 @@ where
 
         Ok(())
     }
+
+    /// Initialise the display like [`init`](DisplayConfig::init), and draw a splash screen, such
+    /// as a logo, to it before turning it on, so the first frame on screen is the splash screen
+    /// rather than the random contents of the display memory.
+    ///
+    /// `splash` is a whole frame in the layout of the display memory, as taken by
+    /// [`draw_frame`](Ssd1306::draw_frame). Returns [`DisplayError::OutOfBoundsError`] without
+    /// initialising the display if `splash` is too short.
+    pub fn init_with_splash(&mut self, splash: &[u8]) -> Result<(), DriverError> {
+        let splash = splash
+            .get(..frame_len(SIZE::WIDTH, SIZE::HEIGHT))
+            .ok_or(DisplayError::OutOfBoundsError)?;
+
+        self.init_impl(AddrMode::Horizontal, Some(splash))
+    }
+
+    /// Draw a whole frame of row major 1 bit per pixel data, as held by an `embedded-graphics`
+    /// `Framebuffer<BinaryColor, ..>` or an `ImageRaw<BinaryColor>`, directly to the display.
+    ///
+    /// Each row of `data` starts at a new byte, and the leftmost pixel of each byte is its most
+    /// significant bit. The rows are as wide as the [dimensions](Ssd1306::dimensions) of the
+    /// display in its current rotation, and they're converted to the layout of the display memory
+    /// a page at a time while sending them.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `data` is too short for a whole frame.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     pixelcolor::{raw::LittleEndian, BinaryColor},
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    /// display.init().unwrap();
+    ///
+    /// let mut frame = Framebuffer::<
+    ///     BinaryColor,
+    ///     _,
+    ///     LittleEndian,
+    ///     128,
+    ///     64,
+    ///     { buffer_size::<BinaryColor>(128, 64) },
+    /// >::new();
+    ///
+    /// Circle::new(Point::new(40, 8), 48)
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut frame)
+    ///     .unwrap();
+    ///
+    /// display.draw_row_major(frame.data()).unwrap();
+    /// ```
+    pub fn draw_row_major(&mut self, data: &[u8]) -> Result<(), DriverError> {
+        let (width, height) = self.dimensions();
+        let stride = (usize::from(width) + 7) / 8;
+
+        if data.len() < stride * usize::from(height) {
+            return Err(DisplayError::OutOfBoundsError.into());
+        }
+
+        let rotation = self.rotation;
+        let pixel = |x: usize, y: usize| data[y * stride + x / 8] >> (7 - x % 8) & 1;
+
+        // A page of the display memory, one byte per column
+        let mut page = [0; 128];
+        let page = &mut page[..usize::from(SIZE::WIDTH)];
+
+        self.draw_whole_display(|display| {
+            for first_row in (0..usize::from(SIZE::HEIGHT)).step_by(8) {
+                for (column, byte) in page.iter_mut().enumerate() {
+                    *byte = (0..8)
+                        .map(|bit| first_row + bit)
+                        .filter(|&row| row < usize::from(SIZE::HEIGHT))
+                        .fold(0, |byte, row| {
+                            // The columns of the display memory are the rows of a display rotated by
+                            // 90 or 270 degrees
+                            let value = match rotation {
+                                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                                    pixel(column, row)
+                                }
+                                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                                    pixel(row, column)
+                                }
+                            };
+
+                            byte | value << (row - first_row)
+                        });
+                }
+
+                display.draw(page)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw a whole frame in the layout of the display memory, such as a frame of an animation
+    /// stored in flash, directly to the display.
+    ///
+    /// `data` holds pages of 8 rows of the display's hardware orientation, from top to bottom,
+    /// like the buffer of [`BufferedGraphicsMode`]. Returns [`DisplayError::OutOfBoundsError`]
+    /// if `data` is too short for a whole frame.
+    pub fn draw_frame(&mut self, data: &[u8]) -> Result<(), DriverError> {
+        let data = data
+            .get(..frame_len(SIZE::WIDTH, SIZE::HEIGHT))
+            .ok_or(DisplayError::OutOfBoundsError)?;
+
+        self.draw_whole_display(|display| display.draw(data))
+    }
+
+    /// Draw a whole [run-length encoded](crate::rle) frame in the layout of the display memory
+    /// directly to the display, decoding it a page at a time.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if `data` decodes to less than a whole frame,
+    /// in which case the frame is partially drawn.
+    pub fn draw_rle_frame(&mut self, data: &[u8]) -> Result<(), DriverError> {
+        let mut decoder = rle::Decoder::new(data);
+
+        // A page of the display memory, one byte per column
+        let mut page = [0; 128];
+        let page = &mut page[..usize::from(SIZE::WIDTH)];
+
+        self.draw_whole_display(|display| {
+            for _ in 0..(SIZE::HEIGHT + 7) / 8 {
+                for byte in page.iter_mut() {
+                    *byte = decoder.next().ok_or(DisplayError::OutOfBoundsError)?;
+                }
+
+                display.draw(page)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Set the draw area to the whole display for the data sent by `draw`
+    fn draw_whole_display(
+        &mut self,
+        draw: impl FnOnce(&mut Self) -> Result<(), DriverError>,
+    ) -> Result<(), DriverError> {
+        let old_addr_mode = self.addr_mode;
+        if old_addr_mode != AddrMode::Horizontal {
+            self.set_addr_mode(AddrMode::Horizontal)?;
+        }
+
+        let (offset_x, offset_y) = self.memory_offsets();
+        self.set_draw_area(
+            (offset_x, offset_y),
+            (offset_x + SIZE::WIDTH, offset_y + SIZE::HEIGHT),
+        )?;
+
+        draw(self)?;
+
+        if old_addr_mode != AddrMode::Horizontal {
+            self.set_addr_mode(old_addr_mode)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, BasicMode>
@@ -65,15 +253,20 @@ where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
 {
-    type Error = DisplayError;
+    type Error = DriverError;
 
     /// Set the display rotation.
-    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DriverError> {
         self.set_rotation(rot)
     }
 
     /// Initialise in horizontal addressing mode.
-    fn init(&mut self) -> Result<(), DisplayError> {
+    fn init(&mut self) -> Result<(), DriverError> {
         self.init_with_addr_mode(AddrMode::Horizontal)
     }
+
+    /// Attach in horizontal addressing mode.
+    fn attach(&mut self) -> Result<(), DriverError> {
+        self.attach_with_addr_mode(AddrMode::Horizontal)
+    }
 }
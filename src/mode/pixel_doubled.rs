@@ -0,0 +1,252 @@
+//! Pixel doubled graphics mode.
+
+use core::marker::PhantomData;
+
+use crate::{
+    command::AddrMode, error::DriverError, error::Operation, rotation::DisplayRotation,
+    size::DisplaySize, trace, Ssd1306,
+};
+use display_interface::{DataFormat::U8, DisplayError, WriteOnlyDataCommand};
+
+use super::DisplayConfig;
+
+/// Pixel doubled graphics mode.
+///
+/// This mode keeps a pixel buffer with half of the width and height of the display, for example
+/// 64x32px on a 128x64px display, where every pixel is shown as a block of 2x2 pixels. The
+/// buffer always uses 256 bytes, a quarter of the buffer of
+/// [`BufferedGraphicsMode`](super::BufferedGraphicsMode) for 128x64px displays.
+///
+/// The buffer is drawn to by [`set_pixel`](Ssd1306::set_pixel) commands or
+/// [`embedded-graphics`](https://docs.rs/embedded-graphics) commands, and each pixel is
+/// doubled when the display is updated using the [`flush`](Ssd1306::flush) method.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_pixel_doubled_mode();
+/// display.init().unwrap();
+///
+/// // Lights up the pixels from (20, 10) to (21, 11) on the display
+/// display.set_pixel(10, 5, true);
+/// display.flush().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct PixelDoubledMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Pixels in the layout of the display memory of a display with half of the size
+    buffer: [u8; 256],
+    /// Area changed since the last flush, in columns and rows of the buffer
+    min_column: u8,
+    max_column: u8,
+    min_row: u8,
+    max_row: u8,
+    size: PhantomData<SIZE>,
+}
+
+impl<SIZE> PixelDoubledMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Create a new pixel doubled graphics mode instance.
+    pub(crate) fn new() -> Self {
+        Self {
+            buffer: [0; 256],
+            min_column: 255,
+            max_column: 0,
+            min_row: 255,
+            max_row: 0,
+            size: PhantomData,
+        }
+    }
+}
+
+impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, PixelDoubledMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Error = DriverError;
+
+    /// Set the display rotation
+    fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DriverError> {
+        self.set_rotation(rot)
+    }
+
+    /// Initialise and clear the display in pixel doubled graphics mode.
+    fn init(&mut self) -> Result<(), DriverError> {
+        self.clear_impl(false);
+        self.init_with_addr_mode(AddrMode::Horizontal)
+    }
+
+    /// Attach to an initialised display in pixel doubled graphics mode without clearing it.
+    fn attach(&mut self) -> Result<(), DriverError> {
+        // Nothing has been drawn to the buffer yet
+        self.mode.min_column = 255;
+        self.mode.max_column = 0;
+        self.mode.min_row = 255;
+        self.mode.max_row = 0;
+        self.attach_with_addr_mode(AddrMode::Horizontal)
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, PixelDoubledMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn clear_impl(&mut self, value: bool) {
+        self.mode.buffer.fill(if value { 0xff } else { 0 });
+
+        self.mode.min_column = 0;
+        self.mode.max_column = SIZE::WIDTH / 2 - 1;
+        self.mode.min_row = 0;
+        self.mode.max_row = SIZE::HEIGHT / 2 - 1;
+    }
+
+    /// Clear the underlying framebuffer. You need to call `disp.flush()` for any effect on the screen.
+    pub fn clear_buffer(&mut self) {
+        self.clear_impl(false);
+    }
+
+    /// Write out data to a display, doubling every pixel.
+    ///
+    /// This only updates the parts of the display that have changed since the last flush.
+    pub fn flush(&mut self) -> Result<(), DriverError> {
+        self.interface.count_flush();
+        self.with_operation(Operation::Flush, Self::flush_impl)
+    }
+
+    /// Write out the frame, see [`flush`](Self::flush)
+    fn flush_impl(&mut self) -> Result<(), DisplayError> {
+        // Nothing to do if no pixels have changed since the last update
+        if self.mode.max_column < self.mode.min_column || self.mode.max_row < self.mode.min_row {
+            return Ok(());
+        }
+
+        // Columns and pages of the display memory to send
+        let columns = self.mode.min_column * 2..=self.mode.max_column * 2 + 1;
+        let pages = self.mode.min_row / 4..=self.mode.max_row / 4;
+
+        self.mode.min_column = 255;
+        self.mode.max_column = 0;
+        self.mode.min_row = 255;
+        self.mode.max_row = 0;
+
+        let (offset_x, offset_y) = self.memory_offsets();
+        self.set_draw_area(
+            (columns.start() + offset_x, pages.start() * 8 + offset_y),
+            (
+                columns.end() + 1 + offset_x,
+                (pages.end() + 1) * 8 + offset_y,
+            ),
+        )?;
+
+        let half_width = usize::from(SIZE::WIDTH / 2);
+        let mut chunk = [0; 128];
+
+        for page in pages {
+            let chunk = &mut chunk[..columns.len()];
+
+            // Each page of the display shows half of a page of the buffer
+            let shift = page % 2 * 4;
+            let half_page = usize::from(page / 2) * half_width;
+
+            for (byte, column) in chunk.iter_mut().zip(columns.clone()) {
+                let nibble = self.mode.buffer[half_page + usize::from(column / 2)] >> shift;
+
+                *byte = (0..4)
+                    .filter(|bit| nibble & (1 << bit) != 0)
+                    .fold(0, |byte, bit| byte | 0b11 << (bit * 2));
+            }
+
+            trace::data(chunk);
+            self.interface.send_data(U8(chunk))?;
+        }
+
+        Ok(())
+    }
+
+    /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
+    /// coordinates are out of the bounds of the display, this method call is a noop.
+    ///
+    /// The coordinates are those of the buffer, which has half of the width and height of the
+    /// display.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        let (column, row) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x),
+        };
+
+        if column >= u32::from(SIZE::WIDTH / 2) || row >= u32::from(SIZE::HEIGHT / 2) {
+            return;
+        }
+
+        let (column, row) = (column as u8, row as u8);
+        let idx = usize::from(row / 8) * usize::from(SIZE::WIDTH / 2) + usize::from(column);
+        let bit = row % 8;
+
+        self.mode.min_column = self.mode.min_column.min(column);
+        self.mode.max_column = self.mode.max_column.max(column);
+        self.mode.min_row = self.mode.min_row.min(row);
+        self.mode.max_row = self.mode.max_row.max(row);
+
+        let byte = &mut self.mode.buffer[idx];
+        *byte = *byte & !(1 << bit) | ((value as u8) << bit);
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, PixelDoubledMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| pos.x >= 0 && pos.y >= 0)
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.clear_impl(color.is_on());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> OriginDimensions for Ssd1306<DI, SIZE, PixelDoubledMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(u32::from(w) / 2, u32::from(h) / 2)
+    }
+}
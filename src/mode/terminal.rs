@@ -1,32 +1,290 @@
-use crate::{command::AddrMode, mode::DisplayConfig, rotation::DisplayRotation, size::*, Ssd1306};
+use crate::{
+    command::AddrMode, error::DriverError, mode::DisplayConfig, rotation::DisplayRotation, size::*,
+    Ssd1306,
+};
 use core::{cmp::min, fmt};
 use display_interface::{DisplayError, WriteOnlyDataCommand};
 
 /// Extends the [`DisplaySize`](crate::size::DisplaySize) trait
 /// to include number of characters that can fit on the display.
+///
+/// The small 72x40 and 64x48 modules show 9 columns of 5 rows and 8 columns of 6 rows of text:
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use core::fmt::Write;
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize72x40, DisplayRotation::Rotate0)
+///     .into_terminal_mode_with_char_buffer();
+/// display.init().unwrap();
+/// display.clear().unwrap();
+/// assert_eq!(display.dimensions_chars(), (9, 5));
+///
+/// // Text wraps to the next row after the 9th column
+/// write!(display, "Temp: 21.5C").unwrap();
+/// assert_eq!(display.char_at(8, 0).unwrap(), '.');
+/// assert_eq!(display.char_at(0, 1).unwrap(), '5');
+/// assert_eq!(display.position().unwrap(), (2, 1));
+///
+/// // Clearing blanks the screen and moves the cursor back to the top left corner
+/// display.clear().unwrap();
+/// assert_eq!(display.char_at(0, 1).unwrap(), ' ');
+/// assert_eq!(display.position().unwrap(), (0, 0));
+/// ```
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use core::fmt::Write;
+/// use ssd1306::{prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize64x48, DisplayRotation::Rotate0)
+///     .into_terminal_mode_with_char_buffer();
+/// display.init().unwrap();
+/// display.clear().unwrap();
+/// assert_eq!(display.dimensions_chars(), (8, 6));
+///
+/// // The cursor wraps from the end of the last row back to the top left corner
+/// display.set_position(6, 5).unwrap();
+/// write!(display, "OK!").unwrap();
+/// assert_eq!(display.char_at(7, 5).unwrap(), 'K');
+/// assert_eq!(display.char_at(0, 0).unwrap(), '!');
+/// assert_eq!(display.position().unwrap(), (1, 0));
+///
+/// display.clear().unwrap();
+/// assert_eq!(display.char_at(7, 5).unwrap(), ' ');
+/// assert_eq!(display.position().unwrap(), (0, 0));
+/// ```
 pub trait TerminalDisplaySize: DisplaySize {
     /// The number of characters that can fit on the display at once (w * h / (8 * 8))
     const CHAR_NUM: u8;
+
+    /// Storage for a shadow copy of the characters on screen, holding `CHAR_NUM` cells
+    type CharBuffer: CharBuffer;
 }
 
 impl TerminalDisplaySize for DisplaySize128x64 {
     const CHAR_NUM: u8 = 128;
+    type CharBuffer = [Cell; Self::CHAR_NUM as usize];
 }
 
 impl TerminalDisplaySize for DisplaySize128x32 {
     const CHAR_NUM: u8 = 64;
+    type CharBuffer = [Cell; Self::CHAR_NUM as usize];
 }
 
 impl TerminalDisplaySize for DisplaySize96x16 {
     const CHAR_NUM: u8 = 24;
+    type CharBuffer = [Cell; Self::CHAR_NUM as usize];
 }
 
 impl TerminalDisplaySize for DisplaySize72x40 {
     const CHAR_NUM: u8 = 45;
+    type CharBuffer = [Cell; Self::CHAR_NUM as usize];
 }
 
 impl TerminalDisplaySize for DisplaySize64x48 {
     const CHAR_NUM: u8 = 48;
+    type CharBuffer = [Cell; Self::CHAR_NUM as usize];
+}
+
+/// A character cell in the shadow character buffer of [`TerminalMode`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Cell {
+    /// Printable ASCII character
+    c: u8,
+    /// Bit 0: inverted, bits 1-2: character size, bits 3-4: part of a scaled character
+    attributes: u8,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell {
+        c: b' ',
+        attributes: 0,
+    };
+
+    /// A cell whose contents on screen are unknown, which never compares equal to printed cells
+    const UNKNOWN: Cell = Cell {
+        c: 0,
+        attributes: 0,
+    };
+
+    fn new(c: char, inverse: bool, char_size: CharSize, tile: u8) -> Self {
+        let c = match c {
+            ' '..='~' => c as u8,
+            _ => b' ',
+        };
+
+        Self {
+            c,
+            attributes: inverse as u8 | (char_size as u8) << 1 | tile << 3,
+        }
+    }
+
+    fn char(self) -> char {
+        self.c.into()
+    }
+
+    fn inverse(self) -> bool {
+        self.attributes & 1 != 0
+    }
+
+    fn char_size(self) -> CharSize {
+        match (self.attributes >> 1) & 0b11 {
+            0 => CharSize::Normal,
+            1 => CharSize::DoubleWidth,
+            2 => CharSize::DoubleHeight,
+            _ => CharSize::DoubleSize,
+        }
+    }
+
+    fn tile(self) -> usize {
+        usize::from((self.attributes >> 3) & 0b11)
+    }
+}
+
+/// Storage for the shadow character buffer of [`TerminalMode`].
+///
+/// This is implemented for `()`, which doesn't store anything, and for arrays of [`Cell`]s.
+pub trait CharBuffer {
+    /// Create a new buffer with all cells blank.
+    fn new_blank() -> Self;
+
+    /// Get the stored cells, or `None` if no characters are stored.
+    fn cells(&self) -> Option<&[Cell]>;
+
+    /// Get the stored cells mutably, or `None` if no characters are stored.
+    fn cells_mut(&mut self) -> Option<&mut [Cell]>;
+}
+
+impl CharBuffer for () {
+    fn new_blank() -> Self {}
+
+    fn cells(&self) -> Option<&[Cell]> {
+        None
+    }
+
+    fn cells_mut(&mut self) -> Option<&mut [Cell]> {
+        None
+    }
+}
+
+impl<const N: usize> CharBuffer for [Cell; N] {
+    fn new_blank() -> Self {
+        [Cell::BLANK; N]
+    }
+
+    fn cells(&self) -> Option<&[Cell]> {
+        Some(self)
+    }
+
+    fn cells_mut(&mut self) -> Option<&mut [Cell]> {
+        Some(self)
+    }
+}
+
+/// Size of the characters printed in terminal mode.
+///
+/// Scaled characters are drawn by stretching the regular 8x8 glyph over multiple character cells,
+/// so a double width character takes up two columns and a double height character takes up two
+/// rows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CharSize {
+    /// Regular 8x8 characters
+    Normal,
+    /// Characters stretched 2x horizontally, spanning two columns
+    DoubleWidth,
+    /// Characters stretched 2x vertically, spanning two rows
+    DoubleHeight,
+    /// Characters stretched 2x in both directions, spanning two columns and two rows
+    DoubleSize,
+}
+
+impl Default for CharSize {
+    fn default() -> Self {
+        CharSize::Normal
+    }
+}
+
+impl CharSize {
+    /// The number of character cells taken up by one character, as (columns, rows)
+    fn cells(self) -> (u8, u8) {
+        match self {
+            CharSize::Normal => (1, 1),
+            CharSize::DoubleWidth => (2, 1),
+            CharSize::DoubleHeight => (1, 2),
+            CharSize::DoubleSize => (2, 2),
+        }
+    }
+}
+
+/// Line wrapping mode for text written with `write_str`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WrapMode {
+    /// Lines are broken at the last character that fits, even in the middle of a word
+    Character,
+    /// Lines are broken at spaces, moving words that don't fit to the next line
+    Word,
+}
+
+impl Default for WrapMode {
+    fn default() -> Self {
+        WrapMode::Character
+    }
+}
+
+/// Policy for text that doesn't fit on a line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Overflow {
+    /// Continue on the next line
+    Wrap,
+    /// Discard everything up to the next line break
+    Clip,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Wrap
+    }
+}
+
+/// Policy for characters that aren't in the font, which covers printable ASCII (`' '` to `'~'`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnknownChar {
+    /// Print the given character instead, or a blank if it isn't in the font either
+    Replace(char),
+    /// Return [`TerminalModeError::UnknownChar`] without printing anything
+    Error,
+}
+
+impl Default for UnknownChar {
+    fn default() -> Self {
+        UnknownChar::Replace('?')
+    }
+}
+
+/// State of the parser for ANSI escape sequences
+#[derive(Debug, Copy, Clone)]
+enum EscapeState {
+    /// Not inside an escape sequence
+    Ground,
+    /// An escape character was received
+    Escape,
+    /// Inside a control sequence, collecting up to two numeric parameters
+    Csi { params: [u8; 2], count: u8 },
+}
+
+impl Default for EscapeState {
+    fn default() -> Self {
+        EscapeState::Ground
+    }
 }
 
 /// Contains the new row that the cursor has wrapped around to
@@ -38,6 +296,8 @@ struct Cursor {
     row: u8,
     width: u8,
     height: u8,
+    /// Number of rows the screen contents need to be scrolled up by
+    scrolled: u8,
 }
 
 impl Cursor {
@@ -49,30 +309,55 @@ impl Cursor {
             row: 0,
             width,
             height,
+            scrolled: 0,
         }
     }
 
-    /// Advances the logical cursor by one character.
+    /// Advances the logical cursor by `columns` characters, moving down by `line_height` rows
+    /// when the end of the line is reached.
     /// Returns a value indicating if this caused the cursor to wrap to the next line or the next
     /// screen.
-    pub fn advance(&mut self) -> Option<CursorWrapEvent> {
-        self.col = (self.col + 1) % self.width;
-        if self.col == 0 {
-            self.row = (self.row + 1) % self.height;
-            Some(CursorWrapEvent(self.row))
+    pub fn advance(
+        &mut self,
+        columns: u8,
+        line_height: u8,
+        scroll: bool,
+    ) -> Option<CursorWrapEvent> {
+        self.col += columns;
+        if self.col >= self.width {
+            Some(self.advance_line(line_height, scroll))
         } else {
             None
         }
     }
 
-    /// Advances the logical cursor to the start of the next line
+    /// Advances the logical cursor to the start of the line `line_height` rows down
+    /// If `scroll` is set, a line that doesn't fit at the bottom of the screen is placed on the
+    /// last rows and the number of rows to scroll up by is recorded, otherwise it wraps to the
+    /// top of the screen.
     /// Returns a value indicating the now active line
-    pub fn advance_line(&mut self) -> CursorWrapEvent {
-        self.row = (self.row + 1) % self.height;
+    pub fn advance_line(&mut self, line_height: u8, scroll: bool) -> CursorWrapEvent {
+        let row = self.row + line_height;
+        let last_row = self.height.saturating_sub(line_height);
+
+        self.row = if !scroll {
+            row % self.height
+        } else if row > last_row {
+            self.scrolled = self.scrolled.saturating_add(row - last_row);
+            last_row
+        } else {
+            row
+        };
         self.col = 0;
         CursorWrapEvent(self.row)
     }
 
+    /// Returns the number of rows the screen contents need to be scrolled up by since the last
+    /// call
+    pub fn take_scrolled(&mut self) -> u8 {
+        core::mem::take(&mut self.scrolled)
+    }
+
     /// Sets the position of the logical cursor arbitrarily.
     /// The position will be capped at the maximal possible position.
     pub fn set_position(&mut self, col: u8, row: u8) {
@@ -95,33 +380,86 @@ impl Cursor {
 #[derive(Clone)]
 pub enum TerminalModeError {
     /// An error occurred in the underlying interface layer
-    InterfaceError(DisplayError),
+    InterfaceError(DriverError),
     /// The mode was used before it was initialized
     Uninitialized,
     /// A location was specified outside the bounds of the screen
     OutOfBounds,
+    /// A character which isn't in the font was printed with [`UnknownChar::Error`] set
+    UnknownChar,
 }
 
-impl core::fmt::Debug for TerminalModeError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl fmt::Debug for TerminalModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
             Self::InterfaceError(_) => "InterfaceError".fmt(f),
             Self::Uninitialized => "Uninitialized".fmt(f),
             Self::OutOfBounds => "OutOfBound".fmt(f),
+            Self::UnknownChar => "UnknownChar".fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TerminalModeError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::InterfaceError(_) => defmt::write!(f, "InterfaceError"),
+            Self::Uninitialized => defmt::write!(f, "Uninitialized"),
+            Self::OutOfBounds => defmt::write!(f, "OutOfBound"),
+            Self::UnknownChar => defmt::write!(f, "UnknownChar"),
         }
     }
 }
 
+impl From<DriverError> for TerminalModeError {
+    fn from(value: DriverError) -> Self {
+        TerminalModeError::InterfaceError(value)
+    }
+}
+
 impl From<DisplayError> for TerminalModeError {
     fn from(value: DisplayError) -> Self {
-        TerminalModeError::InterfaceError(value)
+        TerminalModeError::InterfaceError(value.into())
     }
 }
 
 /// Terminal mode.
+///
+/// By default this mode doesn't keep any buffer in system memory. A shadow copy of the characters
+/// on screen can be kept by creating the display with
+/// [`into_terminal_mode_with_char_buffer`](Ssd1306::into_terminal_mode_with_char_buffer), which
+/// makes [`char_at`](Ssd1306::char_at) and [`screen_contents`](Ssd1306::screen_contents)
+/// available.
+///
+/// All four rotations are supported. In [`Rotate90`](DisplayRotation::Rotate90) and
+/// [`Rotate270`](DisplayRotation::Rotate270) the glyphs are rotated in software and the character
+/// grid is transposed, so a 128x64 display shows 8 columns and 16 rows of text. Changing the
+/// rotation resets the cursor to the top left corner but doesn't clear the screen.
+///
+/// Text can be printed with `core::fmt`'s `write!` macro, or with `ufmt`'s `uwrite!` macro when
+/// the `ufmt` feature is enabled, which avoids pulling the `core::fmt` machinery into the binary.
 #[derive(Debug, Copy, Clone, Default)]
-pub struct TerminalMode {
+pub struct TerminalMode<B = ()> {
     cursor: Option<Cursor>,
+    char_buffer: B,
+    char_size: CharSize,
+    wrap_mode: WrapMode,
+    overflow: Overflow,
+    unknown_char: UnknownChar,
+    /// Scroll the screen contents up instead of wrapping to the top of the screen
+    scrolling: bool,
+    /// The current line overflowed and further characters are discarded
+    clipping: bool,
+    /// The cursor was moved to the next line by wrapping rather than an explicit line break
+    soft_wrapped: bool,
+    /// Characters are printed inverted, set by the `SGR 7` escape sequence
+    inverse: bool,
+    escape: EscapeState,
+    /// The character cell the display's internal draw pointer is at, if known
+    hw_position: Option<(u8, u8)>,
+    /// The rotation the cursor and character grid were laid out for
+    rotation: Option<DisplayRotation>,
 }
 
 impl TerminalMode {
@@ -131,10 +469,35 @@ impl TerminalMode {
     }
 }
 
-impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode>
+impl<B> TerminalMode<B>
+where
+    B: CharBuffer,
+{
+    /// Create a new terminal mode config instance keeping a shadow character buffer.
+    pub(crate) fn with_char_buffer() -> Self {
+        Self {
+            cursor: None,
+            char_buffer: B::new_blank(),
+            char_size: CharSize::default(),
+            wrap_mode: WrapMode::default(),
+            overflow: Overflow::default(),
+            unknown_char: UnknownChar::default(),
+            scrolling: false,
+            clipping: false,
+            soft_wrapped: false,
+            inverse: false,
+            escape: EscapeState::default(),
+            hw_position: None,
+            rotation: None,
+        }
+    }
+}
+
+impl<DI, SIZE, B> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode<B>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    B: CharBuffer,
 {
     type Error = TerminalModeError;
 
@@ -143,6 +506,8 @@ where
     /// This method resets the cursor but does not clear the screen.
     fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), TerminalModeError> {
         self.set_rotation(rot)?;
+        // The characters on screen no longer line up with the character grid
+        self.invalidate_char_buffer();
         // Need to reset cursor position, otherwise coordinates can become invalid
         self.reset_pos()
     }
@@ -152,32 +517,37 @@ where
     /// wrapping.
     fn init(&mut self) -> Result<(), TerminalModeError> {
         self.init_with_addr_mode(AddrMode::Page)?;
+        // The display isn't cleared, so what's on screen is unknown
+        self.invalidate_char_buffer();
+        self.reset_pos()?;
+        Ok(())
+    }
+
+    /// Attach to an initialised display in page mode without clearing it, and move the cursor
+    /// to the top left corner.
+    fn attach(&mut self) -> Result<(), TerminalModeError> {
+        self.attach_with_addr_mode(AddrMode::Page)?;
+        self.invalidate_char_buffer();
         self.reset_pos()?;
         Ok(())
     }
 }
 
-impl<DI, SIZE> Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, B> Ssd1306<DI, SIZE, TerminalMode<B>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    B: CharBuffer,
 {
     /// Clear the display and reset the cursor to the top left corner
     pub fn clear(&mut self) -> Result<(), TerminalModeError> {
         // Let the chip handle line wrapping so we can fill the screen with blanks faster
         self.set_addr_mode(AddrMode::Horizontal)?;
 
-        let offset_x = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-            }
-        };
+        let (offset_x, offset_y) = self.memory_offsets();
         self.set_draw_area(
-            (offset_x, SIZE::OFFSETY),
-            (SIZE::WIDTH + offset_x, SIZE::HEIGHT + SIZE::OFFSETY),
+            (offset_x, offset_y),
+            (SIZE::WIDTH + offset_x, SIZE::HEIGHT + offset_y),
         )?;
 
         // Clear the display
@@ -185,6 +555,10 @@ where
             self.draw(&[0; 8])?;
         }
 
+        if let Some(cells) = self.mode.char_buffer.cells_mut() {
+            cells.fill(Cell::BLANK);
+        }
+
         // But for normal operation we manage the line wrapping
         self.set_addr_mode(AddrMode::Page)?;
         self.reset_pos()?;
@@ -193,41 +567,471 @@ where
     }
 
     /// Print a character to the display
+    ///
+    /// The following subset of ANSI escape sequences is supported, where `ESC` is `'\x1b'` and
+    /// `n` and `m` are optional decimal numbers:
+    ///
+    /// - `ESC[n;mH` and `ESC[n;mf` move the cursor to row `n` and column `m`, counting from 1.
+    /// - `ESC[nA`, `ESC[nB`, `ESC[nC` and `ESC[nD` move the cursor `n` cells up, down, right or
+    ///   left.
+    /// - `ESC[J`, `ESC[1J` and `ESC[2J` clear the screen from the cursor to the end of the screen,
+    ///   from the start of the screen to the cursor, or entirely, without moving the cursor.
+    /// - `ESC[K`, `ESC[1K` and `ESC[2K` clear the current line from the cursor to the end of the
+    ///   line, from the start of the line to the cursor, or entirely.
+    /// - `ESC[7m` prints following characters inverted, and `ESC[27m` or `ESC[0m` return to
+    ///   normal.
+    ///
+    /// Other escape sequences are ignored.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode();
+    /// display.init().unwrap();
+    ///
+    /// write!(display, "\x1b[2J\x1b[3;5H\x1b[7mMENU\x1b[0m").unwrap();
+    /// assert_eq!(display.position().unwrap(), (8, 2));
+    /// ```
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize96x16,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode_with_char_buffer();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// // Clear everything below the cursor in the middle of the first row
+    /// write!(display, "Hello\nworld\x1b[1;3H\x1b[J").unwrap();
+    /// assert_eq!(display.char_at(1, 0).unwrap(), 'e');
+    /// assert_eq!(display.char_at(2, 0).unwrap(), ' ');
+    /// assert_eq!(display.char_at(0, 1).unwrap(), ' ');
+    ///
+    /// // Clear everything above the cursor, which hasn't moved
+    /// write!(display, "\x1b[1J").unwrap();
+    /// assert_eq!(display.char_at(0, 0).unwrap(), ' ');
+    /// assert_eq!(display.position().unwrap(), (2, 0));
+    /// ```
     pub fn print_char(&mut self, c: char) -> Result<(), TerminalModeError> {
+        match self.mode.escape {
+            EscapeState::Ground => {}
+            EscapeState::Escape => {
+                self.mode.escape = if c == '[' {
+                    EscapeState::Csi {
+                        params: [0; 2],
+                        count: 0,
+                    }
+                } else {
+                    EscapeState::Ground
+                };
+
+                return Ok(());
+            }
+            EscapeState::Csi {
+                mut params,
+                mut count,
+            } => {
+                match c {
+                    '0'..='9' => {
+                        if let Some(param) = params.get_mut(usize::from(count)) {
+                            *param = param.saturating_mul(10).saturating_add(c as u8 - b'0');
+                        }
+                        self.mode.escape = EscapeState::Csi { params, count };
+                    }
+                    ';' => {
+                        count = count.saturating_add(1);
+                        self.mode.escape = EscapeState::Csi { params, count };
+                    }
+                    '\x40'..='\x7e' => {
+                        self.mode.escape = EscapeState::Ground;
+                        self.control_sequence(c, params, count)?;
+                    }
+                    // Intermediate and private marker characters are ignored
+                    _ => {}
+                }
+
+                return Ok(());
+            }
+        }
+
         match c {
+            '\x1b' => {
+                self.mode.escape = EscapeState::Escape;
+            }
             '\n' => {
-                let CursorWrapEvent(new_line) = self.ensure_cursor()?.advance_line();
-                self.set_position(0, new_line)?;
+                let (_, line_height) = self.mode.char_size.cells();
+                let scroll = self.mode.scrolling;
+                let CursorWrapEvent(new_line) =
+                    self.ensure_cursor()?.advance_line(line_height, scroll);
+                self.move_cursor(0, new_line)?;
             }
             '\r' => {
-                self.set_column(0)?;
                 let (_, cur_line) = self.ensure_cursor()?.get_position();
-                self.ensure_cursor()?.set_position(0, cur_line);
+                self.move_cursor(0, cur_line)?;
             }
             _ => {
-                let bitmap = match self.rotation {
-                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                        Self::char_to_bitmap(c)
-                    }
-                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                        let bitmap = Self::char_to_bitmap(c);
-                        Self::rotate_bitmap(bitmap)
+                let c = match (c, self.mode.unknown_char) {
+                    (' '..='~', _) => c,
+                    (_, UnknownChar::Replace(replacement)) => replacement,
+                    (_, UnknownChar::Error) => return Err(TerminalModeError::UnknownChar),
+                };
+
+                // Text past the end of a clipped line is discarded until the next line break
+                if self.mode.clipping {
+                    return Ok(());
+                }
+
+                let (char_width, char_height) = self.mode.char_size.cells();
+                let (overflow, scroll) = (self.mode.overflow, self.mode.scrolling);
+
+                let cursor = self.ensure_cursor()?;
+                let (width, height) = cursor.get_dimensions();
+                let (col, _) = cursor.get_position();
+
+                // Handle characters that don't fit on the rest of the current line
+                if col + char_width > width {
+                    match overflow {
+                        Overflow::Wrap => {
+                            let CursorWrapEvent(new_line) =
+                                cursor.advance_line(char_height, scroll);
+                            self.move_cursor(0, new_line)?;
+                        }
+                        Overflow::Clip => {
+                            self.mode.clipping = true;
+                            return Ok(());
+                        }
                     }
+                }
+                let (col, row) = self.ensure_cursor()?.get_position();
+
+                // Make room for characters taller than the rest of the screen
+                let row = if scroll && row + char_height > height {
+                    self.scroll_up(row + char_height - height)?;
+
+                    let row = height.saturating_sub(char_height);
+                    self.ensure_cursor()?.set_position(col, row);
+                    row
+                } else {
+                    row
                 };
 
-                self.draw(&bitmap)?;
+                let mut bitmap = Self::char_to_bitmap(c);
+                if self.mode.inverse {
+                    bitmap.iter_mut().for_each(|column| *column = !*column);
+                }
+
+                let (char_size, inverse) = (self.mode.char_size, self.mode.inverse);
 
-                // Increment character counter and potentially wrap line
-                self.advance_cursor()?;
+                if char_width == 1 && char_height == 1 {
+                    self.draw_cell(col, row, bitmap, Cell::new(c, inverse, char_size, 0))?;
+                } else {
+                    let tiles = Self::scale_bitmap(bitmap, char_size);
+                    for (i, tile) in tiles.iter().enumerate() {
+                        let (tile_col, tile_row) = (col + i as u8 % 2, row + i as u8 / 2);
+
+                        let in_char = tile_col < col + char_width && tile_row < row + char_height;
+
+                        // Parts of the character below the last row are clipped
+                        if in_char && tile_row < height {
+                            let cell = Cell::new(c, inverse, char_size, i as u8);
+                            self.draw_cell(tile_col, tile_row, *tile, cell)?;
+                        }
+                    }
+                }
+                self.mode.soft_wrapped = false;
+
+                if overflow == Overflow::Clip && col + char_width >= width {
+                    // Keep the cursor on the last column instead of wrapping to the next line
+                    self.mode.clipping = true;
+                } else {
+                    // Increment character counter and potentially wrap line
+                    self.advance_cursor(char_width, char_height)?;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Execute an ANSI control sequence with the given final character and parameters
+    fn control_sequence(
+        &mut self,
+        action: char,
+        params: [u8; 2],
+        count: u8,
+    ) -> Result<(), TerminalModeError> {
+        let cursor = self.ensure_cursor()?;
+        let (col, row) = cursor.get_position();
+        let (width, height) = cursor.get_dimensions();
+
+        // Movement amounts and positions default to 1 if omitted
+        let n = params[0].max(1);
+
+        match action {
+            'H' | 'f' => {
+                let col = min(params[1].max(1), width) - 1;
+                let row = min(params[0].max(1), height) - 1;
+                self.move_cursor(col, row)
+            }
+            'A' => self.move_cursor(col, row.saturating_sub(n)),
+            'B' => self.move_cursor(col, min(row.saturating_add(n), height - 1)),
+            'C' => self.move_cursor(min(col.saturating_add(n), width - 1), row),
+            'D' => self.move_cursor(col.saturating_sub(n), row),
+            'J' if params[0] < 2 => {
+                // Cells are cleared in reading order, up to and including the cursor
+                let width = u16::from(width);
+                let cursor = u16::from(row) * width + u16::from(col);
+                let cells = match params[0] {
+                    0 => cursor..width * u16::from(height),
+                    _ => 0..cursor + 1,
+                };
+                for cell in cells {
+                    let (column, line) = ((cell % width) as u8, (cell / width) as u8);
+                    self.draw_cell(column, line, [0; 8], Cell::BLANK)?;
+                }
+                self.move_cursor(col, row)
+            }
+            'J' => {
+                self.clear()?;
+                self.move_cursor(col, row)
+            }
+            'K' => {
+                let (start, end) = match params[0] {
+                    0 => (col, width),
+                    1 => (0, col + 1),
+                    _ => (0, width),
+                };
+                for column in start..end {
+                    self.draw_cell(column, row, [0; 8], Cell::BLANK)?;
+                }
+                self.move_cursor(col, row)
+            }
+            'm' => {
+                for param in params.iter().take(usize::from(count) + 1) {
+                    match param {
+                        0 | 27 => self.mode.inverse = false,
+                        7 => self.mode.inverse = true,
+                        _ => {}
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the size of printed characters.
+    pub fn char_size(&self) -> CharSize {
+        self.mode.char_size
+    }
+
+    /// Set the size of characters printed from now on.
+    ///
+    /// Scaled characters take up multiple character cells. The cursor advances by the width of
+    /// the character, and line breaks move down by its height. Characters that would not fit on
+    /// the rest of the current line are moved to the next line, and the bottom half of double
+    /// height characters printed on the last row is clipped.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{mode::CharSize, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// display.set_char_size(CharSize::DoubleSize);
+    /// write!(display, "12:34").unwrap();
+    /// assert_eq!(display.position().unwrap(), (10, 0));
+    ///
+    /// display.set_char_size(CharSize::Normal);
+    /// write!(display, "\n\nAM").unwrap();
+    /// assert_eq!(display.position().unwrap(), (2, 2));
+    /// ```
+    pub fn set_char_size(&mut self, char_size: CharSize) {
+        self.mode.char_size = char_size;
+    }
+
+    /// Get the line wrapping mode used by `write_str`.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.mode.wrap_mode
+    }
+
+    /// Set the line wrapping mode used by `write_str`.
+    ///
+    /// In [`WrapMode::Word`], lines are broken at spaces so that words which don't fit on the rest
+    /// of the current line are moved to the next line instead of being split. Words are only
+    /// measured within a single `write_str` call, so a word split across multiple calls (e.g.
+    /// between arguments of `write!`) may still be broken mid-word.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{mode::WrapMode, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// display.set_wrap_mode(WrapMode::Word);
+    /// write!(display, "Battery low, connect charger").unwrap();
+    ///
+    /// // "connect" doesn't fit on the first line, so the second line starts with it
+    /// assert_eq!(display.position().unwrap(), (15, 1));
+    /// ```
+    pub fn set_wrap_mode(&mut self, wrap_mode: WrapMode) {
+        self.mode.wrap_mode = wrap_mode;
+    }
+
+    /// Get the overflow policy for text that doesn't fit on a line.
+    pub fn overflow(&self) -> Overflow {
+        self.mode.overflow
+    }
+
+    /// Set the overflow policy for text that doesn't fit on a line.
+    ///
+    /// With [`Overflow::Clip`], text past the end of a line is discarded until the next line
+    /// break. In [`WrapMode::Word`] this only affects words longer than a whole line, which are
+    /// truncated instead of being split over multiple lines.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{mode::Overflow, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// display.set_overflow(Overflow::Clip);
+    /// write!(display, "This line is too long to fit\nNext").unwrap();
+    /// assert_eq!(display.position().unwrap(), (4, 1));
+    /// ```
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.mode.overflow = overflow;
+    }
+
+    /// Get the policy for characters that aren't in the font.
+    pub fn unknown_char(&self) -> UnknownChar {
+        self.mode.unknown_char
+    }
+
+    /// Select the policy for characters that aren't in the font when the display is created.
+    ///
+    /// It can be changed later with [`set_unknown_char`](Ssd1306::set_unknown_char).
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::UnknownChar, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_terminal_mode()
+    /// .with_unknown_char(UnknownChar::Replace('*'));
+    /// display.init().unwrap();
+    ///
+    /// assert_eq!(display.unknown_char(), UnknownChar::Replace('*'));
+    /// ```
+    pub fn with_unknown_char(mut self, unknown_char: UnknownChar) -> Self {
+        self.mode.unknown_char = unknown_char;
+        self
+    }
+
+    /// Set the policy for characters that aren't in the font.
+    ///
+    /// By default these are printed as `'?'`. Note that `write_str` ignores errors, so with
+    /// [`UnknownChar::Error`] it skips unknown characters.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::UnknownChar, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// display.set_unknown_char(UnknownChar::Error);
+    /// assert!(display.print_char('°').is_err());
+    /// assert_eq!(display.position().unwrap(), (0, 0));
+    /// ```
+    pub fn set_unknown_char(&mut self, unknown_char: UnknownChar) {
+        self.mode.unknown_char = unknown_char;
+    }
+
+    /// Get the dimensions of the character grid as (columns, rows), taking the display rotation
+    /// into account.
+    ///
+    /// Each character cell is 8x8 pixels. Characters printed with a [`CharSize`] other than
+    /// [`CharSize::Normal`] cover two cells in width and/or height.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x32,
+    ///     DisplayRotation::Rotate90,
+    /// ).into_terminal_mode();
+    ///
+    /// assert_eq!(display.dimensions_chars(), (4, 16));
+    /// ```
+    pub fn dimensions_chars(&self) -> (u8, u8) {
+        let (w, h) = self.dimensions();
+        (w / 8, h / 8)
+    }
+
     /// Get the current cursor position, in character coordinates.
     /// This is the (column, row) that the next character will be written to.
     pub fn position(&self) -> Result<(u8, u8), TerminalModeError> {
+        if self.layout_changed() {
+            // The cursor is reset to the top left corner by the next print
+            return self
+                .mode
+                .cursor
+                .map(|_| (0, 0))
+                .ok_or(TerminalModeError::Uninitialized);
+        }
+
         self.mode
             .cursor
             .as_ref()
@@ -243,37 +1047,245 @@ where
         if column >= width || row >= height {
             Err(TerminalModeError::OutOfBounds)
         } else {
-            let offset_x = match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-                DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                    // If segment remapping is flipped, we need to calculate
-                    // the offset from the other edge of the display.
-                    SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-                }
-            };
-            match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                    self.set_column(offset_x + column * 8)?;
-                    self.set_row(SIZE::OFFSETY + row * 8)?;
-                }
-                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                    self.set_column(offset_x + row * 8)?;
-                    self.set_row(SIZE::OFFSETY + column * 8)?;
+            self.set_hw_position(column, row)?;
+            self.move_cursor(column, row)
+        }
+    }
+
+    /// Move the logical cursor without moving the display's internal draw pointer, which is
+    /// only updated once something is drawn.
+    fn move_cursor(&mut self, column: u8, row: u8) -> Result<(), TerminalModeError> {
+        let cursor = self.ensure_cursor()?;
+        cursor.set_position(column, row);
+        let scrolled = cursor.take_scrolled();
+
+        self.mode.clipping = false;
+        self.mode.soft_wrapped = false;
+
+        if scrolled > 0 {
+            self.scroll_up(scrolled)?;
+        }
+
+        Ok(())
+    }
+
+    /// Scroll the characters on screen up by the given number of rows, filling the bottom rows
+    /// with blanks.
+    ///
+    /// This redraws the screen from the shadow character buffer, so nothing is scrolled if no
+    /// character buffer is kept.
+    fn scroll_up(&mut self, lines: u8) -> Result<(), TerminalModeError> {
+        if self.mode.char_buffer.cells().is_none() {
+            return Ok(());
+        }
+
+        let (width, height) = self.dimensions_chars();
+        let lines = min(lines, height);
+
+        // Rows are redrawn from the top, so the rows below that are moved up are still intact
+        for row in 0..height {
+            for column in 0..width {
+                let idx = usize::from(row + lines) * usize::from(width) + usize::from(column);
+                let below = self
+                    .mode
+                    .char_buffer
+                    .cells()
+                    .and_then(|cells| cells.get(idx));
+                let cell = match below {
+                    Some(&cell) if row + lines < height && cell != Cell::UNKNOWN => cell,
+                    _ => Cell::BLANK,
+                };
+
+                self.draw_cell(column, row, Self::cell_bitmap(cell), cell)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move the display's internal draw pointer to the given character cell without changing
+    /// the logical cursor.
+    fn set_hw_position(&mut self, column: u8, row: u8) -> Result<(), TerminalModeError> {
+        let (offset_x, offset_y) = self.memory_offsets();
+        match self.rotation() {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                self.set_column(offset_x + column * 8)?;
+                self.set_row(offset_y + row * 8)?;
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                self.set_column(offset_x + row * 8)?;
+                self.set_row(offset_y + column * 8)?;
+            }
+        }
+        self.mode.hw_position = Some((column, row));
+
+        Ok(())
+    }
+
+    /// Mark the contents of all cells in the shadow character buffer as unknown, so they are
+    /// redrawn by the next print
+    fn invalidate_char_buffer(&mut self) {
+        if let Some(cells) = self.mode.char_buffer.cells_mut() {
+            cells.fill(Cell::UNKNOWN);
+        }
+    }
+
+    /// Draw the 8x8 bitmap of `cell` at the given character position.
+    ///
+    /// If a shadow character buffer is kept, the bitmap is only sent to the display if it shows
+    /// something different from what is already on screen.
+    fn draw_cell(
+        &mut self,
+        column: u8,
+        row: u8,
+        bitmap: [u8; 8],
+        cell: Cell,
+    ) -> Result<(), TerminalModeError> {
+        let (width, height) = self.dimensions_chars();
+        let idx = usize::from(row) * usize::from(width) + usize::from(column);
+
+        let stored = self
+            .mode
+            .char_buffer
+            .cells()
+            .and_then(|cells| cells.get(idx));
+        if stored == Some(&cell) {
+            return Ok(());
+        }
+
+        if self.mode.hw_position != Some((column, row)) {
+            self.set_hw_position(column, row)?;
+        }
+
+        let (bitmap, next_position) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                (bitmap, (column + 1 < width).then(|| (column + 1, row)))
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
+                Self::rotate_bitmap(bitmap),
+                (row + 1 < height).then(|| (column, row + 1)),
+            ),
+        };
+
+        self.draw(&bitmap)?;
+
+        // The draw pointer advances along the page, which is the next cell in the same row for
+        // unrotated displays or in the same column for rotated displays
+        self.mode.hw_position = next_position;
+
+        if let Some(slot) = self
+            .mode
+            .char_buffer
+            .cells_mut()
+            .and_then(|cells| cells.get_mut(idx))
+        {
+            *slot = cell;
+        }
+
+        Ok(())
+    }
+
+    /// Print a string using the current wrap mode, ignoring errors like `fmt::Write` does
+    fn print_str(&mut self, s: &str) {
+        match self.mode.wrap_mode {
+            WrapMode::Character => {
+                s.chars().map(move |c| self.print_char(c)).last();
+            }
+            WrapMode::Word => {
+                s.split_inclusive([' ', '\n'])
+                    .map(move |word| self.print_word(word))
+                    .last();
+            }
+        }
+    }
+
+    /// Print a word followed by at most one separator, breaking the line before the word if it
+    /// doesn't fit on the rest of the current line
+    fn print_word(&mut self, word: &str) -> Result<(), TerminalModeError> {
+        let (word, separator) = match word.char_indices().last() {
+            Some((idx, c)) if c == ' ' || c == '\n' => (&word[..idx], Some(c)),
+            _ => (word, None),
+        };
+
+        let scroll = self.mode.scrolling;
+
+        if !word.is_empty() {
+            let (char_width, char_height) = self.mode.char_size.cells();
+            let word_width = Self::printable_len(word) * usize::from(char_width);
+
+            let cursor = self.ensure_cursor()?;
+            let (col, _) = cursor.get_position();
+            let (width, _) = cursor.get_dimensions();
+
+            if col > 0 && usize::from(col) + word_width > usize::from(width) {
+                let CursorWrapEvent(new_line) = cursor.advance_line(char_height, scroll);
+                self.move_cursor(0, new_line)?;
+                self.mode.soft_wrapped = true;
+            }
+
+            for c in word.chars() {
+                self.print_char(c)?;
+            }
+        }
+
+        match separator {
+            // A clipped word ends at the next space, continuing on the next line
+            Some(' ') if self.mode.clipping => {
+                let (_, char_height) = self.mode.char_size.cells();
+                let CursorWrapEvent(new_line) =
+                    self.ensure_cursor()?.advance_line(char_height, scroll);
+                self.move_cursor(0, new_line)?;
+                self.mode.soft_wrapped = true;
+            }
+            // Don't start a wrapped line with a space
+            Some(' ') if self.mode.soft_wrapped => {}
+            Some(c) => self.print_char(c)?,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Count the characters in `s` that would be printed, skipping ANSI escape sequences
+    fn printable_len(s: &str) -> usize {
+        let mut chars = s.chars();
+        let mut len = 0;
+
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                len += 1;
+            } else if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
                 }
             }
-            self.ensure_cursor()?.set_position(column, row);
-            Ok(())
         }
+
+        len
+    }
+
+    /// Reset the text attributes, character size, line wrapping and escape sequence state to their
+    /// defaults
+    #[cfg(feature = "panic-screen")]
+    pub(crate) fn reset_text_state(&mut self) {
+        self.mode.char_size = CharSize::default();
+        self.mode.wrap_mode = WrapMode::default();
+        self.mode.overflow = Overflow::default();
+        self.mode.unknown_char = UnknownChar::default();
+        self.mode.clipping = false;
+        self.mode.soft_wrapped = false;
+        self.mode.inverse = false;
+        self.mode.escape = EscapeState::default();
     }
 
     /// Reset the draw area and move pointer to the top left corner
     fn reset_pos(&mut self) -> Result<(), TerminalModeError> {
         // Initialise the counter when we know it's valid
-        let (w, h) = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
-        };
+        let (w, h) = self.dimensions();
         self.mode.cursor = Some(Cursor::new(w, h));
+        self.mode.rotation = Some(self.rotation);
 
         // Reset cursor position
         self.set_position(0, 0)?;
@@ -281,25 +1293,56 @@ where
         Ok(())
     }
 
-    /// Advance the cursor, automatically wrapping lines and/or screens if necessary
-    /// Takes in an already-unwrapped cursor to avoid re-unwrapping
-    fn advance_cursor(&mut self) -> Result<(), TerminalModeError> {
+    /// Advance the cursor by a character of the given size, automatically wrapping lines and/or
+    /// screens if necessary
+    fn advance_cursor(&mut self, char_width: u8, char_height: u8) -> Result<(), TerminalModeError> {
+        let scroll = self.mode.scrolling;
         let cursor = self.ensure_cursor()?;
 
-        cursor.advance();
+        let wrapped = cursor.advance(char_width, char_height, scroll).is_some();
         let (c, r) = cursor.get_position();
-        self.set_position(c, r)?;
+        self.move_cursor(c, r)?;
+        self.mode.soft_wrapped = wrapped;
 
         Ok(())
     }
 
     fn ensure_cursor(&mut self) -> Result<&mut Cursor, TerminalModeError> {
+        if self.mode.cursor.is_some() && self.layout_changed() {
+            // The rotation was changed with `Ssd1306::set_rotation` instead of
+            // `DisplayConfig::set_rotation`, or the zoom was changed, so the cursor was laid out
+            // for the wrong grid
+            self.invalidate_char_buffer();
+            self.reset_pos()?;
+        }
+
         self.mode
             .cursor
             .as_mut()
             .ok_or(TerminalModeError::Uninitialized)
     }
 
+    /// Check whether the rotation or zoom changed since the cursor was laid out
+    fn layout_changed(&self) -> bool {
+        self.mode.rotation != Some(self.rotation)
+            || self.mode.cursor.as_ref().map_or(false, |cursor| {
+                cursor.get_dimensions() != self.dimensions_chars()
+            })
+    }
+
+    /// Get the 8x8 bitmap shown for a cell of the shadow character buffer
+    fn cell_bitmap(cell: Cell) -> [u8; 8] {
+        let mut bitmap = Self::char_to_bitmap(cell.char());
+        if cell.inverse() {
+            bitmap.iter_mut().for_each(|column| *column = !*column);
+        }
+
+        match cell.char_size() {
+            CharSize::Normal => bitmap,
+            char_size => Self::scale_bitmap(bitmap, char_size)[cell.tile()],
+        }
+    }
+
     fn char_to_bitmap(input: char) -> [u8; 8] {
         const CHARS: [[u8; 6]; 95] = [
             // !
@@ -502,6 +1545,50 @@ where
         [0, g[0], g[1], g[2], g[3], g[4], g[5], 0]
     }
 
+    /// Stretch an 8x8 bitmap into 2x2 tiles for the given character size. Tiles are ordered left
+    /// to right, top to bottom. Tiles not covered by the character size are left empty.
+    fn scale_bitmap(bitmap: [u8; 8], char_size: CharSize) -> [[u8; 8]; 4] {
+        // Doubles every bit of the low nibble, e.g. 0b0101 -> 0b00110011
+        fn stretch_nibble(nibble: u8) -> u8 {
+            (0..4).fold(0, |acc, bit| {
+                if nibble & (1 << bit) != 0 {
+                    acc | (0b11 << (bit * 2))
+                } else {
+                    acc
+                }
+            })
+        }
+
+        let (char_width, char_height) = char_size.cells();
+        let mut tiles = [[0u8; 8]; 4];
+
+        for (i, column) in tiles
+            .iter_mut()
+            .flat_map(|tile| tile.iter_mut())
+            .enumerate()
+        {
+            let (tile, x) = (i / 8, i % 8);
+            let (tile_col, tile_row) = (tile % 2, tile / 2);
+
+            let source = if char_width == 2 {
+                bitmap[(tile_col * 8 + x) / 2]
+            } else if tile_col == 0 {
+                bitmap[x]
+            } else {
+                continue;
+            };
+
+            *column = match (char_height, tile_row) {
+                (2, 0) => stretch_nibble(source),
+                (2, _) => stretch_nibble(source >> 4),
+                (_, 0) => source,
+                _ => 0,
+            };
+        }
+
+        tiles
+    }
+
     fn rotate_bitmap(bitmap: [u8; 8]) -> [u8; 8] {
         let mut rotated: [u8; 8] = [0; 8];
 
@@ -519,13 +1606,159 @@ where
     }
 }
 
-impl<DI, SIZE> core::fmt::Write for Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, const N: usize> Ssd1306<DI, SIZE, TerminalMode<[Cell; N]>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: TerminalDisplaySize,
+{
+    /// Get whether the screen contents scroll up when the cursor moves past the bottom of the
+    /// screen.
+    pub fn scrolling(&self) -> bool {
+        self.mode.scrolling
+    }
+
+    /// Set whether the screen contents scroll up when the cursor moves past the bottom of the
+    /// screen, instead of the cursor wrapping to the top of the screen.
+    ///
+    /// Scrolling redraws every character that changes, so it's considerably slower than printing
+    /// a line.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize96x16,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode_with_char_buffer();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// display.set_scrolling(true);
+    /// write!(display, "one\ntwo\nthree").unwrap();
+    ///
+    /// assert_eq!(display.char_at(0, 0).unwrap(), 't');
+    /// assert_eq!(display.char_at(1, 0).unwrap(), 'w');
+    /// assert_eq!(display.char_at(1, 1).unwrap(), 'h');
+    /// assert_eq!(display.position().unwrap(), (5, 1));
+    /// ```
+    pub fn set_scrolling(&mut self, scrolling: bool) {
+        self.mode.scrolling = scrolling;
+    }
+
+    /// Get the character shown at the given position, in character coordinates.
+    ///
+    /// Cells covered by a scaled character all return that character. Characters without a glyph
+    /// are returned as a space. Cells whose contents are unknown, because the display wasn't
+    /// cleared after [`init`](DisplayConfig::init) or the rotation was changed, return `'\0'`.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode_with_char_buffer();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// write!(display, "Heater: [x]").unwrap();
+    /// assert_eq!(display.char_at(9, 0).unwrap(), 'x');
+    /// assert_eq!(display.char_at(12, 0).unwrap(), ' ');
+    /// ```
+    pub fn char_at(&self, column: u8, row: u8) -> Result<char, TerminalModeError> {
+        let (width, height) = self.dimensions_chars();
+        if column >= width || row >= height {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        let idx = usize::from(row) * usize::from(width) + usize::from(column);
+        self.mode
+            .char_buffer
+            .get(idx)
+            .map(|cell| cell.char())
+            .ok_or(TerminalModeError::OutOfBounds)
+    }
+
+    /// Get the characters on screen, row by row with a `'\n'` after every row.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     interface,
+    ///     DisplaySize96x16,
+    ///     DisplayRotation::Rotate0,
+    /// ).into_terminal_mode_with_char_buffer();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// write!(display, "Hello\nworld").unwrap();
+    ///
+    /// let mut contents = [' '; 26];
+    /// contents
+    ///     .iter_mut()
+    ///     .zip(display.screen_contents())
+    ///     .for_each(|(slot, c)| *slot = c);
+    ///
+    /// assert_eq!(contents[0..5], ['H', 'e', 'l', 'l', 'o']);
+    /// assert_eq!(contents[12], '\n');
+    /// assert_eq!(contents[13..18], ['w', 'o', 'r', 'l', 'd']);
+    /// ```
+    pub fn screen_contents(&self) -> impl Iterator<Item = char> + '_ {
+        let (width, height) = self.dimensions_chars();
+        let width = usize::from(width);
+
+        self.mode
+            .char_buffer
+            .iter()
+            .take(width * usize::from(height))
+            .enumerate()
+            .flat_map(move |(i, cell)| {
+                let line_end = if (i + 1) % width == 0 {
+                    Some('\n')
+                } else {
+                    None
+                };
+
+                core::iter::once(cell.char()).chain(line_end)
+            })
+    }
+}
+
+impl<DI, SIZE, B> fmt::Write for Ssd1306<DI, SIZE, TerminalMode<B>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    B: CharBuffer,
 {
     fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
-        s.chars().map(move |c| self.print_char(c)).last();
+        self.print_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<DI, SIZE, B> ufmt_write::uWrite for Ssd1306<DI, SIZE, TerminalMode<B>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: TerminalDisplaySize,
+    B: CharBuffer,
+{
+    type Error = core::convert::Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.print_str(s);
         Ok(())
     }
 }
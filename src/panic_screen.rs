@@ -0,0 +1,83 @@
+//! Show panic messages on a display in terminal mode.
+//!
+//! This module is enabled by the `panic-screen` feature. A display is registered with
+//! [`set_panic_display`] and can still be printed to by the rest of the application with
+//! [`with_panic_display`]. Calling [`display_panic`] from the application's `#[panic_handler]`
+//! then re-initialises the display and shows the panic message, wrapped over as many lines as
+//! needed.
+//!
+//! Access to the registered display is guarded by a
+//! [`critical-section`](https://docs.rs/critical-section) mutex, so an implementation of
+//! `critical-section` must be linked into the application, for example by enabling the
+//! `critical-section-single-core` feature of `cortex-m`.
+//!
+//! See the `terminal_panic_i2c` example for a complete application.
+
+use crate::{
+    mode::{CharBuffer, DisplayConfig, TerminalDisplaySize, TerminalMode},
+    Ssd1306,
+};
+use core::{cell::RefCell, fmt, panic::PanicInfo};
+use critical_section::Mutex;
+use display_interface::WriteOnlyDataCommand;
+
+/// A display which can show a panic message.
+///
+/// This is implemented for displays in [`TerminalMode`].
+pub trait PanicDisplay: fmt::Write {
+    /// Re-initialise the display and show the message of the given panic on a blank screen.
+    fn show_panic(&mut self, info: &PanicInfo<'_>);
+}
+
+impl<DI, SIZE, B> PanicDisplay for Ssd1306<DI, SIZE, TerminalMode<B>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: TerminalDisplaySize,
+    B: CharBuffer,
+{
+    fn show_panic(&mut self, info: &PanicInfo<'_>) {
+        // The panic may have happened at any point, so start from a known state. Errors are
+        // ignored as there's nothing left to report them to.
+        self.reset_text_state();
+        if self.init().is_err() || self.clear().is_err() {
+            return;
+        }
+
+        let _ = fmt::Write::write_fmt(self, format_args!("PANIC\n{}", info));
+    }
+}
+
+type PanicDisplayRef = &'static mut (dyn PanicDisplay + Send);
+
+static PANIC_DISPLAY: Mutex<RefCell<Option<PanicDisplayRef>>> = Mutex::new(RefCell::new(None));
+
+/// Register the display to show panic messages on, returning the previously registered display.
+pub fn set_panic_display(
+    display: &'static mut (dyn PanicDisplay + Send),
+) -> Option<&'static mut (dyn PanicDisplay + Send)> {
+    critical_section::with(move |cs| PANIC_DISPLAY.borrow(cs).replace(Some(display)))
+}
+
+/// Call `f` with the registered display, for example to print text to it with `write!`.
+///
+/// The display is not available to [`display_panic`] while `f` runs, so a panic in `f` won't be
+/// shown on screen. Returns `None` without calling `f` if no display is registered, or if it is
+/// already in use by an outer call to this function.
+pub fn with_panic_display<R>(f: impl FnOnce(&mut (dyn PanicDisplay + Send)) -> R) -> Option<R> {
+    let display = critical_section::with(|cs| PANIC_DISPLAY.borrow(cs).take())?;
+
+    let result = f(display);
+
+    critical_section::with(move |cs| PANIC_DISPLAY.borrow(cs).replace(Some(display)));
+
+    Some(result)
+}
+
+/// Show the given panic message on the registered display, if any.
+///
+/// This is meant to be called from the application's `#[panic_handler]`.
+pub fn display_panic(info: &PanicInfo<'_>) {
+    if let Some(display) = critical_section::with(|cs| PANIC_DISPLAY.borrow(cs).take()) {
+        display.show_panic(info);
+    }
+}
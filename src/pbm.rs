@@ -0,0 +1,187 @@
+//! Converting PBM images to frames at compile time.
+//!
+//! [`include_frame!`](crate::include_frame) reads a [PBM](https://netpbm.sourceforge.net/doc/pbm.html)
+//! image when the application is compiled, and converts it to an array in the layout of the
+//! display memory, such as a logo for [`draw_frame`](crate::Ssd1306::draw_frame) or a frame of an
+//! [animation](crate::animation). Black pixels of the image are lit on the display. The image
+//! must have the size of the display in its hardware orientation.
+//!
+//! Most image editors can save PBM images, or they can be converted from other formats with
+//! ImageMagick:
+//!
+//! ```text
+//! convert logo.png -monochrome logo.pbm
+//! ```
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use ssd1306::{include_frame, prelude::*, Ssd1306};
+//!
+//! static LOGO: [u8; 1024] = include_frame!(DisplaySize128x64, "../examples/rust_128x64.pbm");
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+//! display.init().unwrap();
+//!
+//! display.draw_frame(&LOGO).unwrap();
+//! ```
+//!
+//! Images which are generated by the application can be converted by
+//! [`frame_from_pbm`] at compile time or at runtime.
+
+/// Read a PBM image as a frame with the size of `SIZE`, in the layout of the display memory.
+///
+/// The path is relative to the current file, like [`include_bytes!`]. Compilation fails if the
+/// image isn't valid or doesn't have the size of the display.
+///
+/// See the [module documentation](crate::pbm) for an example.
+#[macro_export]
+macro_rules! include_frame {
+    ($size:ty, $path:expr) => {{
+        const FRAME: [u8; $crate::pbm::frame_len(
+            <$size as $crate::size::DisplaySize>::WIDTH,
+            <$size as $crate::size::DisplaySize>::HEIGHT,
+        )] = $crate::pbm::frame_from_pbm(
+            include_bytes!($path),
+            <$size as $crate::size::DisplaySize>::WIDTH,
+            <$size as $crate::size::DisplaySize>::HEIGHT,
+        );
+
+        FRAME
+    }};
+}
+
+/// Get the number of bytes of a frame of `width` by `height` pixels.
+pub const fn frame_len(width: u8, height: u8) -> usize {
+    width as usize * ((height as usize + 7) / 8)
+}
+
+/// Convert a PBM image of `width` by `height` pixels to a frame in the layout of the display
+/// memory, with [`frame_len`] bytes. Both the plain (`P1`) and raw (`P4`) formats are supported.
+///
+/// # Panics
+///
+/// Panics if the image isn't valid, if it doesn't have the given size or if `N` isn't the length
+/// of the frame. When called in a constant, this fails the compilation.
+///
+/// ```rust
+/// use ssd1306::pbm::frame_from_pbm;
+///
+/// // An 8x8 arrow pointing up
+/// const ARROW: [u8; 8] = frame_from_pbm(
+///     b"P1
+///     8 8
+///     0 0 0 1 1 0 0 0
+///     0 0 1 1 1 1 0 0
+///     0 1 1 1 1 1 1 0
+///     1 1 1 1 1 1 1 1
+///     0 0 0 1 1 0 0 0
+///     0 0 0 1 1 0 0 0
+///     0 0 0 1 1 0 0 0
+///     0 0 0 1 1 0 0 0",
+///     8,
+///     8,
+/// );
+///
+/// assert_eq!(ARROW, [0x08, 0x0c, 0x0e, 0xff, 0xff, 0x0e, 0x0c, 0x08]);
+/// ```
+pub const fn frame_from_pbm<const N: usize>(pbm: &[u8], width: u8, height: u8) -> [u8; N] {
+    if N != frame_len(width, height) {
+        panic!("frame length doesn't match the size of the display");
+    }
+
+    let raw = if pbm.len() >= 2 && pbm[0] == b'P' && pbm[1] == b'4' {
+        true
+    } else if pbm.len() >= 2 && pbm[0] == b'P' && pbm[1] == b'1' {
+        false
+    } else {
+        panic!("not a PBM image");
+    };
+
+    let (image_width, i) = number(pbm, skip_space(pbm, 2));
+    let (image_height, i) = number(pbm, skip_space(pbm, i));
+    if image_width != width as usize || image_height != height as usize {
+        panic!("size of the image doesn't match the size of the display");
+    }
+
+    let mut frame = [0; N];
+    let width = width as usize;
+    let height = height as usize;
+
+    if raw {
+        // A single whitespace character ends the header, followed by padded rows of pixels
+        let start = i + 1;
+        let stride = (width + 7) / 8;
+        if pbm.len() < start + stride * height {
+            panic!("image data is too short");
+        }
+
+        let mut y = 0;
+        while y < height {
+            let mut x = 0;
+            while x < width {
+                let byte = pbm[start + y * stride + x / 8];
+                if byte & (0x80 >> (x % 8)) != 0 {
+                    frame[y / 8 * width + x] |= 1 << (y % 8);
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+    } else {
+        let mut i = i;
+        let mut pixel = 0;
+        while pixel < width * height {
+            i = skip_space(pbm, i);
+            if i >= pbm.len() {
+                panic!("image data is too short");
+            }
+
+            let (x, y) = (pixel % width, pixel / width);
+            match pbm[i] {
+                b'1' => frame[y / 8 * width + x] |= 1 << (y % 8),
+                b'0' => {}
+                _ => panic!("invalid pixel in image data"),
+            }
+
+            i += 1;
+            pixel += 1;
+        }
+    }
+
+    frame
+}
+
+/// Get the index of the first character after `i` which isn't whitespace or part of a comment
+const fn skip_space(pbm: &[u8], mut i: usize) -> usize {
+    while i < pbm.len() {
+        if pbm[i] == b'#' {
+            while i < pbm.len() && pbm[i] != b'\n' {
+                i += 1;
+            }
+        } else if pbm[i].is_ascii_whitespace() {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+
+    i
+}
+
+/// Parse a decimal number at `i`, returning it and the index after it
+const fn number(pbm: &[u8], mut i: usize) -> (usize, usize) {
+    let start = i;
+    let mut value = 0;
+
+    while i < pbm.len() && pbm[i].is_ascii_digit() {
+        value = value * 10 + (pbm[i] - b'0') as usize;
+        i += 1;
+    }
+
+    if i == start {
+        panic!("invalid PBM header");
+    }
+
+    (value, i)
+}
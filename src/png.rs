@@ -0,0 +1,86 @@
+//! Minimal PNG encoder for screenshots
+
+use std::{io, vec::Vec};
+
+/// Longest block of data in a stored deflate block
+const MAX_STORED_BLOCK: usize = 65535;
+
+/// Write a 1 bit grayscale PNG image of `width` by `height` pixels, where `pixel` gets whether a
+/// pixel is white. The image data is stored without compression.
+pub(crate) fn write_png(
+    mut out: impl io::Write,
+    width: u32,
+    height: u32,
+    pixel: impl Fn(u32, u32) -> bool,
+) -> io::Result<()> {
+    // Rows start with the filter type and are padded to whole bytes
+    let stride = (width as usize + 7) / 8;
+    let mut data = Vec::with_capacity((stride + 1) * height as usize);
+    for y in 0..height {
+        data.push(0);
+        data.extend((0..stride as u32).map(|byte| {
+            (0..8)
+                .filter(|bit| byte * 8 + bit < width && pixel(byte * 8 + bit, y))
+                .fold(0u8, |value, bit| value | 0x80 >> bit)
+        }));
+    }
+
+    // zlib stream of stored deflate blocks
+    let mut zlib = Vec::with_capacity(data.len() + data.len() / MAX_STORED_BLOCK * 5 + 11);
+    zlib.extend([0x78, 0x01]);
+    let count = (data.len() + MAX_STORED_BLOCK - 1) / MAX_STORED_BLOCK;
+    for (i, block) in data.chunks(MAX_STORED_BLOCK).enumerate() {
+        let len = block.len() as u16;
+        // The last block is marked as final
+        zlib.push((i + 1 == count) as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(block);
+    }
+    zlib.extend(adler32(&data).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // Bit depth 1, grayscale, deflate, no filter, not interlaced
+    header.extend([1, 0, 0, 0, 0]);
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    write_chunk(&mut out, b"IHDR", &header)?;
+    write_chunk(&mut out, b"IDAT", &zlib)?;
+    write_chunk(&mut out, b"IEND", &[])
+}
+
+/// Write a chunk of a PNG image
+fn write_chunk(out: &mut impl io::Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+
+    let crc = !kind
+        .iter()
+        .chain(data)
+        .fold(!0, |crc, &byte| crc32_update(crc, byte));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Update a CRC-32 with a byte
+fn crc32_update(crc: u32, byte: u8) -> u32 {
+    (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+        if crc & 1 != 0 {
+            crc >> 1 ^ 0xedb8_8320
+        } else {
+            crc >> 1
+        }
+    })
+}
+
+/// Get the Adler-32 checksum of data
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    b << 16 | a
+}
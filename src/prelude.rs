@@ -6,7 +6,10 @@ pub use display_interface_spi::{SPIInterface, SPIInterfaceNoCS};
 
 pub use super::{
     brightness::Brightness,
+    clock::DisplayClock,
+    error::TakeBusError,
     mode::DisplayConfig,
+    raster_op::RasterOp,
     rotation::DisplayRotation,
     size::{
         DisplaySize, DisplaySize128x32, DisplaySize128x64, DisplaySize64x48, DisplaySize72x40,
@@ -0,0 +1,33 @@
+//! Raster operations
+
+/// How pixels are combined with the pixels already in a buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RasterOp {
+    /// The new pixels replace the pixels in the buffer
+    Replace,
+    /// A pixel is on if it is on in the buffer or in the new pixels
+    Or,
+    /// A pixel is on if it is on in both the buffer and the new pixels
+    And,
+    /// A pixel is on if it is on in either the buffer or the new pixels, but not in both
+    Xor,
+}
+
+impl Default for RasterOp {
+    fn default() -> Self {
+        RasterOp::Replace
+    }
+}
+
+impl RasterOp {
+    /// Combine the bits of `dst` with `src`, for whole bytes of 8 pixels
+    pub(crate) fn apply(self, dst: u8, src: u8) -> u8 {
+        match self {
+            RasterOp::Replace => src,
+            RasterOp::Or => dst | src,
+            RasterOp::And => dst & src,
+            RasterOp::Xor => dst ^ src,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Reset pin configuration.
+
+/// Configuration of the reset pin and the timing of the reset pulse sent by
+/// [`Ssd1306::reset_with_config`](crate::Ssd1306::reset_with_config).
+///
+/// The default configuration is an active low reset pin, held inactive for 1 ms before a 10 ms
+/// reset pulse, with no delay after the pulse.
+///
+/// ```rust
+/// use ssd1306::reset::ResetConfig;
+///
+/// // Reset pin driven through an inverting transistor
+/// let config = ResetConfig::new()
+///     .active_high(true)
+///     .pulse_ms(5)
+///     .settle_ms(2);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResetConfig {
+    pub(crate) active_high: bool,
+    pub(crate) idle_ms: u8,
+    pub(crate) pulse_ms: u8,
+    pub(crate) settle_ms: u8,
+}
+
+impl Default for ResetConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResetConfig {
+    /// Create the default reset configuration.
+    pub const fn new() -> Self {
+        Self {
+            active_high: false,
+            idle_ms: 1,
+            pulse_ms: 10,
+            settle_ms: 0,
+        }
+    }
+
+    /// Set whether the display is reset by driving the pin high instead of low.
+    pub const fn active_high(mut self, active_high: bool) -> Self {
+        self.active_high = active_high;
+        self
+    }
+
+    /// Set how long the pin is held inactive before the reset pulse, in milliseconds.
+    pub const fn idle_ms(mut self, idle_ms: u8) -> Self {
+        self.idle_ms = idle_ms;
+        self
+    }
+
+    /// Set the length of the reset pulse, in milliseconds.
+    pub const fn pulse_ms(mut self, pulse_ms: u8) -> Self {
+        self.pulse_ms = pulse_ms;
+        self
+    }
+
+    /// Set how long to wait after the reset pulse for the display to start, in milliseconds.
+    pub const fn settle_ms(mut self, settle_ms: u8) -> Self {
+        self.settle_ms = settle_ms;
+        self
+    }
+}
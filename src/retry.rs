@@ -0,0 +1,157 @@
+//! Retrying failed transfers to the display.
+//!
+//! A [`RetryInterface`] wraps the interface of a display, and repeats command and data transfers
+//! which fail with [`DisplayError::BusWriteError`], for example because an I2C write wasn't
+//! acknowledged due to noise or a display which was just plugged in. Only when a transfer still
+//! fails after the configured number of retries is the error returned.
+//!
+//! A hook can be called before every retry, for example to wait for the bus to settle:
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::{DelayStub, I2cStub};
+//! # let i2c = I2cStub;
+//! # let mut delay = DelayStub;
+//! use embedded_hal::blocking::delay::DelayMs;
+//! use ssd1306::{prelude::*, retry::RetryInterface, I2CDisplayInterface, Ssd1306};
+//!
+//! // Retry every transfer up to 3 times, waiting a bit longer each time
+//! let interface = RetryInterface::new(I2CDisplayInterface::new(i2c), 3)
+//!     .with_hook(|attempt| delay.delay_ms(attempt * 2));
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//! ```
+
+use core::fmt;
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+
+use crate::{error::TakeBusError, status::DetectNoAcknowledge};
+
+/// Number of data bytes in one transfer of the I2C interface
+const DATA_CHUNK: usize = 16;
+
+/// An interface which retries transfers that fail with [`DisplayError::BusWriteError`].
+///
+/// Data is passed on to the wrapped interface in pieces of 16 bytes, the size of a transfer of
+/// the I2C interface, so that the data written by the transfers before a failed one isn't
+/// written again at the wrong address.
+///
+/// See the [module level documentation](crate::retry) for an example.
+pub struct RetryInterface<DI, F = fn(u8)> {
+    iface: DI,
+    retries: u8,
+    hook: F,
+}
+
+impl<DI> RetryInterface<DI> {
+    /// Wrap an interface, repeating each failed transfer up to `retries` times.
+    pub fn new(iface: DI, retries: u8) -> Self {
+        Self {
+            iface,
+            retries,
+            hook: |_| {},
+        }
+    }
+}
+
+impl<DI, F> RetryInterface<DI, F> {
+    /// Call `hook` before every retry, with the number of the retry starting at 1.
+    pub fn with_hook<F2>(self, hook: F2) -> RetryInterface<DI, F2>
+    where
+        F2: FnMut(u8),
+    {
+        RetryInterface {
+            iface: self.iface,
+            retries: self.retries,
+            hook,
+        }
+    }
+
+    /// Release the contained interface.
+    pub fn release(self) -> DI {
+        self.iface
+    }
+}
+
+impl<DI, F> RetryInterface<DI, F>
+where
+    DI: WriteOnlyDataCommand,
+    F: FnMut(u8),
+{
+    /// Call `send` until it doesn't fail with a bus error, or there are no retries left.
+    fn retry(
+        &mut self,
+        mut send: impl FnMut(&mut DI) -> Result<(), DisplayError>,
+    ) -> Result<(), DisplayError> {
+        let mut attempt = 0;
+
+        loop {
+            match send(&mut self.iface) {
+                Err(DisplayError::BusWriteError) if attempt < self.retries => {
+                    attempt += 1;
+                    (self.hook)(attempt);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<DI, F> WriteOnlyDataCommand for RetryInterface<DI, F>
+where
+    DI: WriteOnlyDataCommand,
+    F: FnMut(u8),
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        match cmd {
+            DataFormat::U8(bytes) => self.retry(|iface| iface.send_commands(DataFormat::U8(bytes))),
+            // Iterators can't be sent again, and the other formats aren't used by the driver
+            other => self.iface.send_commands(other),
+        }
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        match buf {
+            DataFormat::U8(bytes) => bytes
+                .chunks(DATA_CHUNK)
+                .try_for_each(|chunk| self.retry(|iface| iface.send_data(DataFormat::U8(chunk)))),
+            // Iterators can't be sent again, and the other formats aren't used by the driver
+            other => self.iface.send_data(other),
+        }
+    }
+}
+
+/// The error of the bus is the one of the last failed attempt of a transfer.
+impl<DI, F> TakeBusError for RetryInterface<DI, F>
+where
+    DI: TakeBusError,
+{
+    type BusError = DI::BusError;
+
+    fn take_bus_error(&mut self) -> Option<DI::BusError> {
+        self.iface.take_bus_error()
+    }
+}
+
+impl<DI, F> DetectNoAcknowledge for RetryInterface<DI, F>
+where
+    DI: DetectNoAcknowledge,
+{
+    fn no_acknowledge(&self) -> bool {
+        self.iface.no_acknowledge()
+    }
+}
+
+impl<DI, F> fmt::Debug for RetryInterface<DI, F>
+where
+    DI: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryInterface")
+            .field("iface", &self.iface)
+            .field("retries", &self.retries)
+            .finish()
+    }
+}
@@ -0,0 +1,137 @@
+//! Run-length encoded frames.
+//!
+//! Frames in the layout of the display memory often have long runs of identical bytes, such as
+//! blank areas, so animations with many frames can be stored in much less flash by
+//! [encoding](encode) them. The frames are decoded while they're sent to the display by
+//! [`draw_rle_frame`](crate::Ssd1306::draw_rle_frame) or an
+//! [animation](crate::animation::Frame::rle), without a buffer.
+//!
+//! The encoding is a sequence of packets, each starting with a control byte. If the most
+//! significant bit of the control byte is set, the next byte is repeated `(control & 0x7f) + 1`
+//! times. Otherwise, the next `control + 1` bytes are copied as they are.
+//!
+//! The encoder doesn't need an allocator, so it can be used by build scripts and host tools
+//! which depend on this crate as well as on the target:
+//!
+//! ```rust
+//! use ssd1306::rle;
+//!
+//! // A frame of a 128x64 display with a line across the middle
+//! let mut frame = [0; 1024];
+//! frame[512..640].fill(0x01);
+//!
+//! let mut encoded = [0; rle::max_encoded_len(1024)];
+//! let len = rle::encode(&frame, &mut encoded).unwrap();
+//! assert_eq!(len, 16);
+//!
+//! assert!(rle::Decoder::new(&encoded[..len]).eq(frame.iter().copied()));
+//! ```
+
+/// Longest run or literal in a packet
+const MAX_PACKET: usize = 128;
+
+/// Shortest run which is encoded as a run, rather than as part of a literal
+const MIN_RUN: usize = 3;
+
+/// Get the largest number of bytes `len` bytes can be encoded to.
+pub const fn max_encoded_len(len: usize) -> usize {
+    len + (len + MAX_PACKET - 1) / MAX_PACKET
+}
+
+/// Encode `data` to `out`, returning the length of the encoded data, or `None` if `out` is too
+/// short. An `out` of [`max_encoded_len`] bytes is always long enough.
+pub fn encode(data: &[u8], out: &mut [u8]) -> Option<usize> {
+    let mut len = 0;
+    let mut push = |bytes: &[u8]| {
+        out.get_mut(len..len + bytes.len())?.copy_from_slice(bytes);
+        len += bytes.len();
+        Some(())
+    };
+
+    let run_at = |start: usize| {
+        data[start..]
+            .iter()
+            .take(MAX_PACKET)
+            .take_while(|&&byte| byte == data[start])
+            .count()
+    };
+
+    let mut start = 0;
+    while start < data.len() {
+        let run = run_at(start);
+
+        if run >= MIN_RUN {
+            push(&[0x80 | (run - 1) as u8, data[start]])?;
+            start += run;
+            continue;
+        }
+
+        // Bytes up to the next run which is worth encoding
+        let mut end = start + run;
+        while end < data.len() && end - start < MAX_PACKET && run_at(end) < MIN_RUN {
+            end += run_at(end);
+        }
+        let end = end.min(start + MAX_PACKET);
+
+        push(&[(end - start - 1) as u8])?;
+        push(&data[start..end])?;
+        start = end;
+    }
+
+    Some(len)
+}
+
+/// Iterator over the bytes of [encoded](encode) data.
+///
+/// The iterator ends early if the encoded data is truncated.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    data: &'a [u8],
+    /// Bytes left in the current packet
+    remaining: u8,
+    /// Byte repeated by the current packet, if it's a run
+    repeat: Option<u8>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Decode `data`.
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            remaining: 0,
+            repeat: None,
+        }
+    }
+
+    /// Take the next byte of the encoded data
+    fn take(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.data.split_first()?;
+        self.data = rest;
+
+        Some(byte)
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.remaining == 0 {
+            let control = self.take()?;
+
+            self.repeat = if control & 0x80 != 0 {
+                Some(self.take()?)
+            } else {
+                None
+            };
+            self.remaining = (control & 0x7f) + 1;
+        }
+
+        self.remaining -= 1;
+
+        match self.repeat {
+            Some(byte) => Some(byte),
+            None => self.take(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Drawing with enlarged pixels.
+//!
+//! [`Scaled`] wraps a [`DrawTarget`], such as a display in
+//! [buffered graphics mode](crate::mode::BufferedGraphicsMode), and draws every pixel as a
+//! square block of pixels, for example for a retro look without scaling every primitive by hand.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle},
+//! };
+//! use ssd1306::{prelude::*, scaled::Scaled, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // A 32x16 drawing surface with 4x4px pixels
+//! let mut scaled = Scaled::new(&mut display, 4);
+//! assert_eq!(scaled.size(), Size::new(32, 16));
+//!
+//! Circle::new(Point::new(2, 2), 10)
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut scaled)
+//!     .unwrap();
+//!
+//! display.flush().unwrap();
+//! ```
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// A [`DrawTarget`] which draws every pixel as a square block of pixels of the wrapped target.
+///
+/// See the [module documentation](crate::scaled) for an example.
+#[derive(Debug)]
+pub struct Scaled<'a, T> {
+    target: &'a mut T,
+    scale: u32,
+}
+
+impl<'a, T> Scaled<'a, T>
+where
+    T: DrawTarget + OriginDimensions,
+{
+    /// Draw to `target` with pixels of `scale` by `scale` pixels. A scale of 0 is treated as 1.
+    pub fn new(target: &'a mut T, scale: u8) -> Self {
+        Self {
+            target,
+            scale: scale.max(1).into(),
+        }
+    }
+
+    /// Get the size of a pixel, in pixels of the wrapped target.
+    pub fn scale(&self) -> u8 {
+        self.scale as u8
+    }
+
+    /// Get the area of the wrapped target covered by an area
+    fn scale_area(&self, area: &Rectangle) -> Rectangle {
+        Rectangle::new(area.top_left * self.scale as i32, area.size * self.scale)
+    }
+}
+
+impl<T> DrawTarget for Scaled<'_, T>
+where
+    T: DrawTarget + OriginDimensions,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let scale = self.scale as i32;
+
+        self.target
+            .draw_iter(pixels.into_iter().flat_map(move |Pixel(pos, color)| {
+                (0..scale * scale)
+                    .map(move |i| Pixel(pos * scale + Point::new(i % scale, i / scale), color))
+            }))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.scale_area(area);
+
+        self.target.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
+
+impl<T> OriginDimensions for Scaled<'_, T>
+where
+    T: DrawTarget + OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.target.size() / self.scale
+    }
+}
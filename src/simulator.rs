@@ -0,0 +1,414 @@
+//! A simulated display for developing applications on a desktop.
+//!
+//! [`Simulator`] is an interface that decodes the command and data stream sent by the driver,
+//! the same way the SSD1306 does, into an emulated display RAM. Because it sits below the driver,
+//! all of the addressing, rotation and flushing logic of the driver runs unchanged, and the
+//! simulator shows exactly what a real panel would.
+//!
+//! The simulator implements [`Display`](core::fmt::Display) to render the panel to a terminal,
+//! using half block characters to show two rows of pixels on each line. The alternate form
+//! (`{:#}`) renders one row per line using `#` for lit pixels and `.` for unlit ones.
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{PrimitiveStyle, Rectangle},
+//! };
+//! use ssd1306::{prelude::*, simulator::Simulator, Ssd1306};
+//!
+//! let interface = Simulator::new(DisplaySize96x16);
+//! let mut display = Ssd1306::new(interface, DisplaySize96x16, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! Rectangle::new(Point::new(0, 0), Size::new(4, 2))
+//!     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+//!     .draw(&mut display)
+//!     .unwrap();
+//! display.flush().unwrap();
+//!
+//! let simulator = display.interface();
+//! assert!(simulator.pixel(3, 1));
+//! assert!(!simulator.pixel(4, 1));
+//!
+//! // Print the panel to the terminal
+//! println!("{}", simulator);
+//! ```
+//!
+//! Like the parallel interfaces of the display, the simulator can read back the display memory,
+//! for example to [`verify`](crate::Ssd1306::verify) the transfers to it, and its
+//! [status](crate::status).
+//!
+//! ```rust
+//! use ssd1306::{prelude::*, simulator::Simulator, Ssd1306};
+//!
+//! let interface = Simulator::new(DisplaySize128x32);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate90)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! display.set_pixel(10, 20, true);
+//! assert_eq!(display.flush_verified().unwrap(), None);
+//!
+//! // Changes which weren't flushed are found as well
+//! display.set_pixel(11, 20, true);
+//! assert!(display.verify().unwrap().is_some());
+//! ```
+//!
+//! Scrolling, fading and zooming are recorded but not animated, and the panel is rendered as if
+//! they were disabled.
+
+use core::fmt;
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+
+use crate::{
+    size::DisplaySize,
+    status::{ReadMemory, ReadStatus},
+};
+
+/// Number of pages of display RAM
+const PAGES: usize = 8;
+
+/// Number of columns of display RAM
+const COLUMNS: usize = 128;
+
+/// Number of rows of display RAM
+const ROWS: u8 = 64;
+
+/// Longest command, including its arguments
+const MAX_COMMAND_LEN: usize = 7;
+
+/// An interface that emulates an SSD1306 and the panel connected to it.
+///
+/// See the [module level documentation](crate::simulator) for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct Simulator {
+    ram: [[u8; COLUMNS]; PAGES],
+    width: u8,
+    height: u8,
+    panel_height: u8,
+    driver_cols: u8,
+    offset_x: u8,
+    offset_y: u8,
+    command: [u8; MAX_COMMAND_LEN],
+    command_len: usize,
+    column: u8,
+    page: u8,
+    addr_mode: u8,
+    columns: (u8, u8),
+    pages: (u8, u8),
+    segment_remap: bool,
+    reverse_com: bool,
+    start_line: u8,
+    multiplex: u8,
+    display_offset: u8,
+    display_on: bool,
+    all_on: bool,
+    invert: bool,
+    contrast: u8,
+    scrolling: bool,
+}
+
+impl Simulator {
+    /// Create a new simulator for a panel of the given size.
+    ///
+    /// The simulated display starts in the state of a display that has just been reset, with a
+    /// cleared display RAM.
+    pub fn new<SIZE: DisplaySize>(_size: SIZE) -> Self {
+        let mut simulator = Self {
+            ram: [[0; COLUMNS]; PAGES],
+            width: SIZE::WIDTH,
+            height: SIZE::HEIGHT,
+            panel_height: SIZE::PANEL_HEIGHT,
+            driver_cols: SIZE::DRIVER_COLS,
+            offset_x: SIZE::OFFSETX,
+            offset_y: SIZE::OFFSETY,
+            command: [0; MAX_COMMAND_LEN],
+            command_len: 0,
+            column: 0,
+            page: 0,
+            addr_mode: 0,
+            columns: (0, 0),
+            pages: (0, 0),
+            segment_remap: false,
+            reverse_com: false,
+            start_line: 0,
+            multiplex: 0,
+            display_offset: 0,
+            display_on: false,
+            all_on: false,
+            invert: false,
+            contrast: 0,
+            scrolling: false,
+        };
+        simulator.reset();
+
+        simulator
+    }
+
+    /// Reset the registers of the simulated display to their power on values.
+    ///
+    /// Like a hardware reset, this leaves the contents of the display RAM unchanged.
+    pub fn reset(&mut self) {
+        self.command_len = 0;
+        self.column = 0;
+        self.page = 0;
+        self.addr_mode = 0b10;
+        self.columns = (0, COLUMNS as u8 - 1);
+        self.pages = (0, PAGES as u8 - 1);
+        self.segment_remap = false;
+        self.reverse_com = false;
+        self.start_line = 0;
+        self.multiplex = ROWS - 1;
+        self.display_offset = 0;
+        self.display_on = false;
+        self.all_on = false;
+        self.invert = false;
+        self.contrast = 0x7F;
+        self.scrolling = false;
+    }
+
+    /// Get the width and height of the panel in pixels.
+    pub fn size(&self) -> (u8, u8) {
+        (self.width, self.height)
+    }
+
+    /// Get a pixel of the display RAM, addressed by column and row.
+    ///
+    /// Returns `false` for a pixel outside the display RAM.
+    pub fn ram_pixel(&self, column: u8, row: u8) -> bool {
+        let (column, row) = (usize::from(column), usize::from(row));
+        if column >= COLUMNS || row >= usize::from(ROWS) {
+            return false;
+        }
+
+        self.ram[row / 8][column] & (1 << (row % 8)) != 0
+    }
+
+    /// Get whether the pixel at `x`, `y` of the panel is lit.
+    ///
+    /// The coordinates are relative to the top left corner of the panel as seen with
+    /// [`DisplayRotation::Rotate0`](crate::rotation::DisplayRotation::Rotate0). This takes the
+    /// segment remap, COM scan direction, start line, multiplex ratio, display offset, inversion
+    /// and the display on and entire display on commands into account.
+    pub fn pixel(&self, x: u8, y: u8) -> bool {
+        if x >= self.width || y >= self.height || !self.display_on {
+            return false;
+        }
+        if self.all_on {
+            return true;
+        }
+
+        // The panels are wired so that the segments and COM lines run the opposite way to the
+        // rows and columns of the panel
+        let segment = self.driver_cols - 1 - self.offset_x - x;
+        let com = self.panel_height.saturating_sub(1 + self.offset_y + y);
+        if com > self.multiplex {
+            return false;
+        }
+
+        let column = if self.segment_remap {
+            self.driver_cols - 1 - segment
+        } else {
+            segment
+        };
+        let com = if self.reverse_com {
+            self.multiplex - com
+        } else {
+            com
+        };
+        let row = (com + self.start_line + self.display_offset) % ROWS;
+
+        self.ram_pixel(column, row) != self.invert
+    }
+
+    /// Get whether the display is switched on.
+    pub fn is_display_on(&self) -> bool {
+        self.display_on
+    }
+
+    /// Get whether the display colors are inverted.
+    pub fn is_inverted(&self) -> bool {
+        self.invert
+    }
+
+    /// Get whether hardware scrolling is enabled.
+    pub fn is_scrolling(&self) -> bool {
+        self.scrolling
+    }
+
+    /// Get the contrast of the display.
+    pub fn contrast(&self) -> u8 {
+        self.contrast
+    }
+
+    /// Number of bytes of the command starting with `first`, including its arguments.
+    fn command_len(first: u8) -> usize {
+        match first {
+            0x26 | 0x27 => 7,
+            0x29 | 0x2A => 6,
+            0x21 | 0x22 | 0xA3 => 3,
+            0x20 | 0x23 | 0x81 | 0x8D | 0xA8 | 0xAD | 0xD3 | 0xD5 | 0xD6 | 0xD9 | 0xDA | 0xDB => 2,
+            _ => 1,
+        }
+    }
+
+    /// Run a complete command.
+    fn execute(&mut self) {
+        let command = self.command;
+
+        match command[0] {
+            c @ 0x00..=0x0F => self.column = (self.column & 0xF0) | c,
+            c @ 0x10..=0x1F => self.column = (self.column & 0x0F) | ((c & 0xF) << 4),
+            0x20 => self.addr_mode = command[1] & 0b11,
+            0x21 => {
+                self.columns = (command[1] & 0x7F, command[2] & 0x7F);
+                self.column = self.columns.0;
+            }
+            0x22 => {
+                self.pages = (command[1] & 0x7, command[2] & 0x7);
+                self.page = self.pages.0;
+            }
+            0x26 | 0x27 | 0x29 | 0x2A => self.scrolling = false,
+            c @ 0x2E..=0x2F => self.scrolling = c & 1 != 0,
+            c @ 0x40..=0x7F => self.start_line = c & 0x3F,
+            0x81 => self.contrast = command[1],
+            c @ 0xA0..=0xA1 => self.segment_remap = c & 1 != 0,
+            c @ 0xA4..=0xA5 => self.all_on = c & 1 != 0,
+            c @ 0xA6..=0xA7 => self.invert = c & 1 != 0,
+            0xA8 => self.multiplex = (command[1] & 0x3F).max(15),
+            c @ 0xAE..=0xAF => self.display_on = c & 1 != 0,
+            c @ 0xB0..=0xB7 => self.page = c & 0x7,
+            c @ 0xC0..=0xCF => self.reverse_com = c & 0x8 != 0,
+            0xD3 => self.display_offset = command[1] & 0x3F,
+            // Everything else only affects the analog side of the display, or is not emulated
+            _ => {}
+        }
+    }
+
+    /// Decode command bytes, which may end in the middle of a command.
+    fn send_command_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.command[self.command_len] = byte;
+            self.command_len += 1;
+
+            if self.command_len == Self::command_len(self.command[0]) {
+                self.execute();
+                self.command_len = 0;
+            }
+        }
+    }
+
+    /// Write a byte to the display RAM and advance the address pointers.
+    fn write_data(&mut self, byte: u8) {
+        self.ram[usize::from(self.page)][usize::from(self.column)] = byte;
+        self.advance();
+    }
+
+    /// Advance the address pointers after a byte of the display RAM was written or read.
+    fn advance(&mut self) {
+        match self.addr_mode {
+            // Horizontal
+            0b00 => {
+                if self.column >= self.columns.1 {
+                    self.column = self.columns.0;
+                    self.page = if self.page >= self.pages.1 {
+                        self.pages.0
+                    } else {
+                        self.page + 1
+                    };
+                } else {
+                    self.column += 1;
+                }
+            }
+            // Vertical
+            0b01 => {
+                if self.page >= self.pages.1 {
+                    self.page = self.pages.0;
+                    self.column = if self.column >= self.columns.1 {
+                        self.columns.0
+                    } else {
+                        self.column + 1
+                    };
+                } else {
+                    self.page += 1;
+                }
+            }
+            // Page, and the invalid mode which behaves the same
+            _ => self.column = (self.column + 1) % COLUMNS as u8,
+        }
+    }
+}
+
+impl WriteOnlyDataCommand for Simulator {
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        match cmd {
+            DataFormat::U8(bytes) => self.send_command_bytes(bytes),
+            DataFormat::U8Iter(bytes) => bytes.for_each(|byte| self.send_command_bytes(&[byte])),
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        }
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        match buf {
+            DataFormat::U8(bytes) => bytes.iter().for_each(|&byte| self.write_data(byte)),
+            DataFormat::U8Iter(bytes) => bytes.for_each(|byte| self.write_data(byte)),
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        }
+
+        Ok(())
+    }
+}
+
+impl ReadMemory for Simulator {
+    fn read_memory(&mut self, buffer: &mut [u8]) -> Result<(), DisplayError> {
+        for byte in buffer {
+            *byte = self.ram[usize::from(self.page)][usize::from(self.column)];
+            self.advance();
+        }
+
+        Ok(())
+    }
+}
+
+impl ReadStatus for Simulator {
+    fn read_status(&mut self) -> Result<u8, DisplayError> {
+        Ok(if self.display_on { 0 } else { 0x40 })
+    }
+}
+
+impl fmt::Display for Simulator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    f.write_str(if self.pixel(x, y) { "#" } else { "." })?;
+                }
+                f.write_str("\n")?;
+            }
+
+            return Ok(());
+        }
+
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let top = self.pixel(x, y);
+                let bottom = y + 1 < self.height && self.pixel(x, y + 1);
+
+                f.write_str(match (top, bottom) {
+                    (true, true) => "\u{2588}",
+                    (true, false) => "\u{2580}",
+                    (false, true) => "\u{2584}",
+                    (false, false) => " ",
+                })?;
+            }
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
@@ -19,6 +19,39 @@ impl<const N: usize> NewZeroed for [u8; N] {
 ///
 /// This trait describes information related to a particular display.
 /// This includes resolution, offset and framebuffer size.
+///
+/// A display size can also describe a window of a larger panel, so the driver only keeps a
+/// buffer for the window and leaves the rest of the display memory untouched. The panel is still
+/// configured with all of its rows.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use display_interface::{DisplayError, WriteOnlyDataCommand};
+/// use ssd1306::{command::Command, prelude::*, size::DisplaySize, Ssd1306};
+///
+/// /// The top 16 rows of a 128x64 display
+/// struct StatusStrip;
+///
+/// impl DisplaySize for StatusStrip {
+///     const WIDTH: u8 = 128;
+///     const HEIGHT: u8 = 16;
+///     const PANEL_HEIGHT: u8 = 64;
+///     type Buffer = [u8; Self::WIDTH as usize * Self::HEIGHT as usize / 8];
+///
+///     fn configure(&self, iface: &mut impl WriteOnlyDataCommand) -> Result<(), DisplayError> {
+///         Command::ComPinConfig(true, false).send(iface)
+///     }
+/// }
+///
+/// // Only needs a 256 byte buffer
+/// let mut display = Ssd1306::new(interface, StatusStrip, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+///
+/// display.set_pixel(10, 10, true);
+/// display.flush().unwrap();
+/// ```
 pub trait DisplaySize {
     /// Width in pixels
     const WIDTH: u8;
@@ -26,6 +59,10 @@ pub trait DisplaySize {
     /// Height in pixels
     const HEIGHT: u8;
 
+    /// Height in pixels of the panel, if the display size only describes a window of the first
+    /// `HEIGHT` rows after `OFFSETY` of it
+    const PANEL_HEIGHT: u8 = Self::HEIGHT;
+
     /// Maximum width supported by the display driver
     const DRIVER_COLS: u8 = 128;
 
@@ -35,12 +72,13 @@ pub trait DisplaySize {
     /// Horizontal offset in pixels
     const OFFSETX: u8 = 0;
 
-    /// Vertical offset in pixels
+    /// Vertical offset in pixels, which must be a multiple of 8 as the display memory is drawn to
+    /// in pages of 8 rows
     const OFFSETY: u8 = 0;
 
     /// Size of framebuffer. Because the display is monochrome, this is
     /// width * height / 8
-    type Buffer: AsMut<[u8]> + NewZeroed;
+    type Buffer: AsMut<[u8]> + AsRef<[u8]> + NewZeroed;
 
     /// Send resolution and model-dependent configuration to the display
     ///
@@ -52,6 +90,7 @@ pub trait DisplaySize {
 
 /// Size information for the common 128x64 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize128x64;
 impl DisplaySize for DisplaySize128x64 {
     const WIDTH: u8 = 128;
@@ -65,6 +104,7 @@ impl DisplaySize for DisplaySize128x64 {
 
 /// Size information for the common 128x32 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize128x32;
 impl DisplaySize for DisplaySize128x32 {
     const WIDTH: u8 = 128;
@@ -78,6 +118,7 @@ impl DisplaySize for DisplaySize128x32 {
 
 /// Size information for the common 96x16 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize96x16;
 impl DisplaySize for DisplaySize96x16 {
     const WIDTH: u8 = 96;
@@ -91,6 +132,7 @@ impl DisplaySize for DisplaySize96x16 {
 
 /// Size information for the common 72x40 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize72x40;
 impl DisplaySize for DisplaySize72x40 {
     const WIDTH: u8 = 72;
@@ -107,6 +149,7 @@ impl DisplaySize for DisplaySize72x40 {
 
 /// Size information for the common 64x48 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize64x48;
 impl DisplaySize for DisplaySize64x48 {
     const WIDTH: u8 = 64;
@@ -122,6 +165,7 @@ impl DisplaySize for DisplaySize64x48 {
 
 /// Size information for the common 64x32 variants
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisplaySize64x32;
 impl DisplaySize for DisplaySize64x32 {
     const WIDTH: u8 = 64;
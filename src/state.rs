@@ -0,0 +1,58 @@
+//! Display state snapshot
+
+use crate::{
+    brightness::Brightness,
+    clock::DisplayClock,
+    command::{AddrMode, VcomhLevel},
+    rotation::DisplayRotation,
+};
+
+/// Snapshot of the display settings cached by the driver, taken with
+/// [`Ssd1306::save_state`](crate::Ssd1306::save_state) and sent back to the display with
+/// [`Ssd1306::restore_state`](crate::Ssd1306::restore_state).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DisplayState {
+    pub(crate) rotation: DisplayRotation,
+    pub(crate) addr_mode: AddrMode,
+    pub(crate) brightness: Brightness,
+    pub(crate) invert: bool,
+    pub(crate) display_on: bool,
+    pub(crate) zoom_in: bool,
+    pub(crate) precharge: Option<(u8, u8)>,
+    pub(crate) vcomh: VcomhLevel,
+    pub(crate) clock: DisplayClock,
+    pub(crate) flip: (bool, bool),
+}
+
+impl DisplayState {
+    /// Get the display rotation.
+    pub fn rotation(&self) -> DisplayRotation {
+        self.rotation
+    }
+
+    /// Get the addressing mode.
+    pub fn addr_mode(&self) -> AddrMode {
+        self.addr_mode
+    }
+
+    /// Get the brightness.
+    pub fn brightness(&self) -> Brightness {
+        self.brightness
+    }
+
+    /// Get whether the display is inverted.
+    pub fn invert(&self) -> bool {
+        self.invert
+    }
+
+    /// Get whether the display is on.
+    pub fn display_on(&self) -> bool {
+        self.display_on
+    }
+
+    /// Get whether the display is flipped horizontally and vertically.
+    pub fn flip(&self) -> (bool, bool) {
+        self.flip
+    }
+}
@@ -0,0 +1,183 @@
+//! Counters and error context of the transfers to the display
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+
+use crate::error::{ErrorContext, Operation, Transfer};
+
+/// Counters of the transfers to the display since they were last reset, read with
+/// [`Ssd1306::stats`](crate::Ssd1306::stats).
+///
+/// A transaction is a single transfer of command or data bytes over the interface, for example
+/// one I2C write.
+#[cfg(feature = "stats")]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Stats {
+    pub(crate) flushes: u32,
+    pub(crate) bytes: u32,
+    pub(crate) command_transactions: u32,
+    pub(crate) data_transactions: u32,
+    pub(crate) largest_transaction: usize,
+}
+
+#[cfg(feature = "stats")]
+impl Stats {
+    /// Get the number of flushes, of any mode.
+    pub fn flushes(&self) -> u32 {
+        self.flushes
+    }
+
+    /// Get the number of command and data bytes written.
+    pub fn bytes_written(&self) -> u32 {
+        self.bytes
+    }
+
+    /// Get the number of transactions of command bytes. Several commands can be sent in one
+    /// transaction.
+    pub fn commands_sent(&self) -> u32 {
+        self.command_transactions
+    }
+
+    /// Get the number of transactions of data bytes.
+    pub fn data_sent(&self) -> u32 {
+        self.data_transactions
+    }
+
+    /// Get the number of bytes of the largest transaction.
+    pub fn largest_transaction(&self) -> usize {
+        self.largest_transaction
+    }
+
+    /// Count a transaction of `len` bytes.
+    fn count(&mut self, len: usize) {
+        self.bytes = self.bytes.saturating_add(len as u32);
+        self.largest_transaction = self.largest_transaction.max(len);
+    }
+}
+
+/// The interface of a display, recording the context of the last error and counting the
+/// transfers if the `stats` feature is enabled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Counting<DI> {
+    pub(crate) iface: DI,
+    pub(crate) operation: Operation,
+    pub(crate) last_error: Option<ErrorContext>,
+    #[cfg(feature = "stats")]
+    pub(crate) stats: Stats,
+}
+
+impl<DI> Counting<DI> {
+    pub(crate) fn new(iface: DI) -> Self {
+        Self {
+            iface,
+            operation: Operation::Other,
+            last_error: None,
+            #[cfg(feature = "stats")]
+            stats: Stats::default(),
+        }
+    }
+
+    /// Record the context of a transfer if it failed, or forget the last error if it didn't.
+    fn record(
+        &mut self,
+        result: Result<(), DisplayError>,
+        transfer: Transfer,
+    ) -> Result<(), DisplayError> {
+        self.last_error = result.as_ref().err().map(|_| ErrorContext {
+            operation: self.operation,
+            transfer,
+        });
+
+        result
+    }
+
+    /// Count a flush of the display.
+    pub(crate) fn count_flush(&mut self) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.flushes = self.stats.flushes.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "stats"))]
+impl<DI> Counting<DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Send a transaction with `send`.
+    fn send(
+        &mut self,
+        format: DataFormat<'_>,
+        send: impl FnOnce(&mut DI, DataFormat<'_>) -> Result<(), DisplayError>,
+    ) -> Result<(), DisplayError> {
+        send(&mut self.iface, format)
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<DI> Counting<DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Send a transaction with `send`, and count its bytes.
+    fn send(
+        &mut self,
+        format: DataFormat<'_>,
+        send: impl FnOnce(&mut DI, DataFormat<'_>) -> Result<(), DisplayError>,
+    ) -> Result<(), DisplayError> {
+        let len = match format {
+            DataFormat::U8(bytes) => bytes.len(),
+            DataFormat::U16(words) => words.len() * 2,
+            DataFormat::U16BE(ref words) | DataFormat::U16LE(ref words) => words.len() * 2,
+            DataFormat::U8Iter(iter) => {
+                let mut len = 0;
+                send(
+                    &mut self.iface,
+                    DataFormat::U8Iter(&mut iter.inspect(|_| len += 1)),
+                )?;
+                self.stats.count(len);
+
+                return Ok(());
+            }
+            // Iterators of words aren't counted
+            _ => 0,
+        };
+
+        send(&mut self.iface, format)?;
+        self.stats.count(len);
+
+        Ok(())
+    }
+}
+
+impl<DI> WriteOnlyDataCommand for Counting<DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.command_transactions = self.stats.command_transactions.saturating_add(1);
+        }
+
+        let opcode = match cmd {
+            DataFormat::U8(bytes) => bytes.first().copied(),
+            _ => None,
+        };
+        let result = self.send(cmd, DI::send_commands);
+
+        self.record(result, Transfer::Command(opcode))
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.data_transactions = self.stats.data_transactions.saturating_add(1);
+        }
+
+        let result = self.send(buf, DI::send_data);
+
+        self.record(result, Transfer::Data)
+    }
+}
@@ -0,0 +1,220 @@
+//! Detecting the display and reading its status.
+//!
+//! [`Ssd1306::probe`] checks whether a display is connected at all, for products where the
+//! display is an optional module. A missing display is told apart from other errors of the bus
+//! by interfaces which implement [`DetectNoAcknowledge`], such as the
+//! [`I2cInterface`](crate::hal1::I2cInterface) for `embedded-hal` 1.0 buses:
+//!
+//! ```rust
+//! # #[cfg(feature = "embedded-hal-1")] {
+//! # use ssd1306::test_helpers::I2cStub;
+//! # let i2c = I2cStub;
+//! use ssd1306::{hal1::I2cInterface, prelude::*, status::Presence, Ssd1306};
+//!
+//! let interface = I2cInterface::new(i2c);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+//!
+//! match display.probe() {
+//!     Ok(Presence::Connected) => display.init().unwrap(),
+//!     Ok(Presence::NotConnected) => { /* Run without a display */ }
+//!     Err(error) => { /* The bus failed, for example after an arbitration loss */ }
+//! }
+//! # }
+//! ```
+//!
+//! The display can report whether it is switched on, which lets an application check the state
+//! of the panel after a brownout or other power event instead of assuming it. Reading the status
+//! needs an interface which implements [`ReadStatus`], such as the I2C interface created by
+//! [`I2CDisplayInterface::new_readable`](crate::I2CDisplayInterface::new_readable).
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::I2cStub;
+//! # let i2c = I2cStub;
+//! use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306};
+//!
+//! let interface = I2CDisplayInterface::new_readable(i2c);
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+//! display.init().unwrap();
+//!
+//! // Initialise the display again if it was reset by a power event
+//! if !display.read_status().unwrap().is_display_on() {
+//!     display.init().unwrap();
+//! }
+//! ```
+
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+use display_interface_i2c::I2CInterface;
+use display_interface_spi::{SPIInterface, SPIInterfaceNoCS};
+
+use crate::{command::Command, error::DriverError, Ssd1306};
+
+/// Whether a display answered a probe, returned by [`Ssd1306::probe`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Presence {
+    /// The display acknowledged the probe
+    Connected,
+    /// No display acknowledged the probe
+    NotConnected,
+}
+
+/// An interface which can tell whether a failed transfer wasn't acknowledged, as when there is no
+/// display at the address, which [`Ssd1306::probe`] uses to tell a missing display apart from
+/// other errors of the bus.
+///
+/// The I2C interfaces of this crate tell from the error of the bus:
+///
+/// - The [`hal1::I2cInterface`](crate::hal1::I2cInterface) checks for the
+///   [`NoAcknowledge`](embedded_hal_1::i2c::ErrorKind::NoAcknowledge) error kind.
+/// - `embedded-hal` 0.2 has no common error kinds, so the
+///   [`ReadableI2CInterface`](crate::ReadableI2CInterface) and the
+///   [`ContinuousI2CInterface`](crate::ContinuousI2CInterface) check errors which implement
+///   [`AcknowledgeError`], such as those of the
+///   [`SoftwareI2c`](crate::bitbang::SoftwareI2c) bus.
+///
+/// The [`I2CInterface`](crate::I2CInterface) of `display-interface-i2c` doesn't keep the error of
+/// the bus, so it can't tell, and the SPI interfaces have no acknowledgements. They never report
+/// a failed transfer as not acknowledged.
+pub trait DetectNoAcknowledge {
+    /// Get whether the last failed transfer failed because it wasn't acknowledged.
+    fn no_acknowledge(&self) -> bool;
+}
+
+/// An error of an I2C bus of `embedded-hal` 0.2, which can tell whether a write wasn't
+/// acknowledged.
+pub trait AcknowledgeError {
+    /// Get whether the error is a write which wasn't acknowledged.
+    fn is_no_acknowledge(&self) -> bool;
+}
+
+impl<I2C> DetectNoAcknowledge for I2CInterface<I2C> {
+    fn no_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+impl<SPI, DC, CS> DetectNoAcknowledge for SPIInterface<SPI, DC, CS> {
+    fn no_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+impl<SPI, DC> DetectNoAcknowledge for SPIInterfaceNoCS<SPI, DC> {
+    fn no_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+/// An interface which can read the status byte of the display.
+pub trait ReadStatus {
+    /// Read the status byte of the display.
+    fn read_status(&mut self) -> Result<u8, DisplayError>;
+}
+
+/// An interface which can read the display memory, which is only possible over the parallel
+/// interfaces of the display.
+///
+/// This is used by [`verify`](crate::Ssd1306::verify) to read back the contents of the display.
+pub trait ReadMemory {
+    /// Fill `buffer` with bytes of the display memory, starting at the address set by the last
+    /// command.
+    ///
+    /// The display returns an invalid byte for the first read after the address is set, which
+    /// the implementation has to read and discard.
+    fn read_memory(&mut self, buffer: &mut [u8]) -> Result<(), DisplayError>;
+}
+
+/// A byte of the display memory which doesn't match the buffer, found by
+/// [`verify`](crate::Ssd1306::verify).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Mismatch {
+    pub(crate) page: u8,
+    pub(crate) column: u8,
+}
+
+impl Mismatch {
+    /// Get the page of the display memory.
+    pub fn page(&self) -> u8 {
+        self.page
+    }
+
+    /// Get the column of the display memory.
+    pub fn column(&self) -> u8 {
+        self.column
+    }
+}
+
+/// The status byte of the display, read with [`Ssd1306::read_status`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Status(u8);
+
+impl Status {
+    /// Create a status from the byte read from the display.
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Get the byte read from the display.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Get whether the display is switched on.
+    pub const fn is_display_on(self) -> bool {
+        self.0 & 0x40 == 0
+    }
+
+    /// Get whether the display is busy.
+    ///
+    /// The SSD1306 reserves this bit, but some compatible controllers set it while they are
+    /// busy.
+    pub const fn is_busy(self) -> bool {
+        self.0 & 0x80 != 0
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand + DetectNoAcknowledge,
+{
+    /// Check whether a display is connected, by sending it a
+    /// [`Noop`](crate::command::Command::Noop) command which doesn't change its state.
+    ///
+    /// A write which the interface reports as [not acknowledged](DetectNoAcknowledge), as when
+    /// there is no display at the address, is returned as [`Presence::NotConnected`]. Other
+    /// errors, including errors of interfaces which can't tell why a write failed, are returned
+    /// as they are. As they don't have a way to acknowledge writes, SPI displays are always
+    /// reported as connected unless the bus fails.
+    ///
+    /// See the [`status`](crate::status) module for an example.
+    pub fn probe(&mut self) -> Result<Presence, DriverError> {
+        self.with_context(|display| match Command::Noop.send(&mut display.interface) {
+            Ok(()) => Ok(Presence::Connected),
+            Err(DisplayError::BusWriteError) if display.interface.iface.no_acknowledge() => {
+                Ok(Presence::NotConnected)
+            }
+            Err(error) => Err(error),
+        })
+    }
+
+    /// Check whether a display is connected, treating any error as no display.
+    ///
+    /// See [`probe`](Self::probe) for details.
+    pub fn is_connected(&mut self) -> bool {
+        matches!(self.probe(), Ok(Presence::Connected))
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand + ReadStatus,
+{
+    /// Read the status of the display.
+    ///
+    /// See the [`status`](crate::status) module for an example.
+    pub fn read_status(&mut self) -> Result<Status, DriverError> {
+        self.with_context(|display| display.interface.iface.read_status().map(Status::from_bits))
+    }
+}
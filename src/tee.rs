@@ -0,0 +1,124 @@
+//! Drawing to two targets at once.
+//!
+//! [`Tee`] forwards everything drawn to it to a primary [`DrawTarget`], such as the display, and
+//! to a secondary one, for example a simulator window or a framebuffer which is sent to a host
+//! for debugging layouts on setups where the display is hard to see.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! # let mirror_interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{PrimitiveStyle, Rectangle},
+//! };
+//! use ssd1306::{prelude::*, tee::Tee, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! let mut mirror = Ssd1306::new(mirror_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! mirror.init().unwrap();
+//!
+//! let mut tee = Tee::new(&mut display, &mut mirror);
+//! Rectangle::new(Point::new(10, 10), Size::new(20, 20))
+//!     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+//!     .draw(&mut tee)
+//!     .unwrap();
+//!
+//! display.flush().unwrap();
+//! mirror.flush().unwrap();
+//! ```
+
+use core::iter;
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget, geometry::Dimensions, primitives::Rectangle, Pixel,
+};
+
+/// Error returned by one of the targets of a [`Tee`]
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TeeError<A, B> {
+    /// Error of the primary target
+    Primary(A),
+    /// Error of the secondary target
+    Secondary(B),
+}
+
+/// A [`DrawTarget`] which draws to two targets.
+///
+/// The size of the tee is the size of the primary target. Pixels outside of the secondary
+/// target are clipped by it.
+///
+/// See the [module documentation](crate::tee) for an example.
+#[derive(Debug)]
+pub struct Tee<'a, A, B> {
+    primary: &'a mut A,
+    secondary: &'a mut B,
+}
+
+impl<'a, A, B> Tee<'a, A, B>
+where
+    A: DrawTarget,
+    B: DrawTarget<Color = A::Color>,
+{
+    /// Draw to both `primary` and `secondary`.
+    pub fn new(primary: &'a mut A, secondary: &'a mut B) -> Self {
+        Self { primary, secondary }
+    }
+}
+
+impl<A, B> DrawTarget for Tee<'_, A, B>
+where
+    A: DrawTarget,
+    B: DrawTarget<Color = A::Color>,
+{
+    type Color = A::Color;
+    type Error = TeeError<A::Error, B::Error>;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        pixels.into_iter().try_for_each(|pixel| {
+            self.primary
+                .draw_iter(iter::once(pixel))
+                .map_err(TeeError::Primary)?;
+            self.secondary
+                .draw_iter(iter::once(pixel))
+                .map_err(TeeError::Secondary)
+        })
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.primary
+            .fill_solid(area, color)
+            .map_err(TeeError::Primary)?;
+        self.secondary
+            .fill_solid(area, color)
+            .map_err(TeeError::Secondary)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        // Only the area of the primary target is cleared on the secondary one
+        let area = self.primary.bounding_box();
+
+        self.primary.clear(color).map_err(TeeError::Primary)?;
+        self.secondary
+            .fill_solid(&area, color)
+            .map_err(TeeError::Secondary)
+    }
+}
+
+impl<A, B> Dimensions for Tee<'_, A, B>
+where
+    A: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.primary.bounding_box()
+    }
+}
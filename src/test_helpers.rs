@@ -3,6 +3,7 @@
 use display_interface::{DisplayError, WriteOnlyDataCommand};
 use embedded_hal::{
     blocking::{
+        delay::{DelayMs, DelayUs},
         i2c,
         spi::{self, Transfer},
     },
@@ -41,6 +42,26 @@ impl i2c::Write for I2cStub {
     }
 }
 
+impl i2c::WriteIter for I2cStub {
+    type Error = ();
+
+    fn write<B>(&mut self, _addr: u8, _bytes: B) -> Result<(), ()>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        Ok(())
+    }
+}
+
+impl i2c::Read for I2cStub {
+    type Error = ();
+
+    fn read(&mut self, _addr: u8, buf: &mut [u8]) -> Result<(), ()> {
+        buf.fill(0);
+        Ok(())
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct PinStub;
@@ -57,6 +78,20 @@ impl OutputPin for PinStub {
     }
 }
 
+// Reads low, so that the writes of a software I2C bus are acknowledged
+#[cfg(feature = "bitbang")]
+impl embedded_hal::digital::v2::InputPin for PinStub {
+    type Error = ();
+
+    fn is_high(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_low(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct StubInterface;
@@ -72,3 +107,124 @@ impl WriteOnlyDataCommand for StubInterface {
         Ok(())
     }
 }
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub struct DelayStub;
+
+impl DelayMs<u8> for DelayStub {
+    fn delay_ms(&mut self, _ms: u8) {}
+}
+
+impl DelayUs<u8> for DelayStub {
+    fn delay_us(&mut self, _us: u8) {}
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::i2c::ErrorType for I2cStub {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::i2c::I2c for I2cStub {
+    fn transaction(
+        &mut self,
+        _addr: u8,
+        operations: &mut [embedded_hal_1::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            if let embedded_hal_1::i2c::Operation::Read(buf) = operation {
+                buf.fill(0);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::spi::ErrorType for SpiStub {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::spi::SpiDevice for SpiStub {
+    fn transaction(
+        &mut self,
+        _operations: &mut [embedded_hal_1::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::digital::ErrorType for PinStub {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::digital::OutputPin for PinStub {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-hal-1")]
+impl embedded_hal_1::spi::SpiBus for SpiStub {
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        words.fill(0);
+        Ok(())
+    }
+
+    fn write(&mut self, _words: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn transfer(&mut self, read: &mut [u8], _write: &[u8]) -> Result<(), Self::Error> {
+        read.fill(0);
+        Ok(())
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        words.fill(0);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for I2cStub {
+    async fn transaction(
+        &mut self,
+        _addr: u8,
+        operations: &mut [embedded_hal_1::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            if let embedded_hal_1::i2c::Operation::Read(buf) = operation {
+                buf.fill(0);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::spi::SpiDevice for SpiStub {
+    async fn transaction(
+        &mut self,
+        _operations: &mut [embedded_hal_1::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for DelayStub {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
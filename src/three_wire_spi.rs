@@ -0,0 +1,185 @@
+//! 3-wire SPI interface
+
+use core::fmt;
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_hal::{blocking::spi, digital::v2::OutputPin};
+
+use crate::{error::TakeBusError, status::DetectNoAcknowledge};
+
+/// Number of 9-bit words packed into one SPI write
+const WORDS: usize = 64;
+
+/// [`Noop`](crate::command::Command::Noop) command, used to fill up the last byte of a transfer
+const NOOP: u16 = 0xE3;
+
+/// SPI interface for displays strapped for 3-wire SPI, which have no data/command pin.
+///
+/// In 3-wire mode, every byte is sent as a 9-bit word whose first bit selects between command
+/// and data. This interface packs the words into bytes, so that the display can be used with any
+/// SPI bus which sends 8-bit words. As the number of bits of a write has to be a multiple of 8,
+/// transfers are padded with [`Noop`](crate::command::Command::Noop) commands, which don't change
+/// the state of the display.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::{PinStub, SpiStub};
+/// # let (spi, cs) = (SpiStub, PinStub);
+/// use ssd1306::{prelude::*, Ssd1306, ThreeWireSPIInterface};
+///
+/// let interface = ThreeWireSPIInterface::new(spi, cs);
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+/// ```
+///
+/// Errors of the SPI bus are returned as [`DisplayError::BusWriteError`] by the driver, but the
+/// original error of the last failed transfer is kept, and can be taken with
+/// [`take_bus_error`](TakeBusError::take_bus_error).
+pub struct ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8>,
+{
+    spi: SPI,
+    cs: CS,
+    bus_error: Option<SPI::Error>,
+}
+
+impl<SPI, CS> ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8>,
+    CS: OutputPin,
+{
+    /// Create a new 3-wire SPI interface with a chip select pin.
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Self {
+            spi,
+            cs,
+            bus_error: None,
+        }
+    }
+
+    /// Release the contained SPI bus and chip select pin.
+    pub fn release(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    /// Send `bytes` as 9-bit words with the D/C# bit set to `data`.
+    fn send(&mut self, data: bool, format: DataFormat<'_>) -> Result<(), DisplayError> {
+        let bytes = match format {
+            DataFormat::U8(bytes) => bytes,
+            _ => return Err(DisplayError::DataFormatNotImplemented),
+        };
+
+        self.cs.set_low().map_err(|_| DisplayError::CSError)?;
+        let result = bytes
+            .chunks(WORDS)
+            .try_for_each(|chunk| self.write_words(data, chunk));
+        self.cs.set_high().ok();
+
+        result
+    }
+
+    /// Pack a chunk of bytes into 9-bit words and write them.
+    fn write_words(&mut self, data: bool, chunk: &[u8]) -> Result<(), DisplayError> {
+        let mut buffer = [0; WORDS / 8 * 9];
+        let mut len = 0;
+        let mut bits = 0u32;
+        let mut pending = 0;
+
+        // Fill up the last byte with Noop commands
+        let padding = (8 - chunk.len() % 8) % 8;
+        let words = chunk
+            .iter()
+            .map(|&byte| u16::from(data) << 8 | u16::from(byte))
+            .chain(core::iter::repeat(NOOP).take(padding));
+
+        for word in words {
+            bits = bits << 9 | u32::from(word);
+            pending += 9;
+
+            while pending >= 8 {
+                pending -= 8;
+                buffer[len] = (bits >> pending) as u8;
+                len += 1;
+            }
+            bits &= (1 << pending) - 1;
+        }
+
+        self.spi.write(&buffer[..len]).map_err(|error| {
+            self.bus_error = Some(error);
+            DisplayError::BusWriteError
+        })
+    }
+}
+
+impl<SPI, CS> TakeBusError for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8>,
+{
+    type BusError = SPI::Error;
+
+    fn take_bus_error(&mut self) -> Option<SPI::Error> {
+        self.bus_error.take()
+    }
+}
+
+impl<SPI, CS> DetectNoAcknowledge for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8>,
+{
+    fn no_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+impl<SPI, CS> fmt::Debug for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8> + fmt::Debug,
+    SPI::Error: fmt::Debug,
+    CS: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreeWireSPIInterface")
+            .field("spi", &self.spi)
+            .field("cs", &self.cs)
+            .field("bus_error", &self.bus_error)
+            .finish()
+    }
+}
+
+impl<SPI, CS> Clone for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8> + Clone,
+    SPI::Error: Clone,
+    CS: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            spi: self.spi.clone(),
+            cs: self.cs.clone(),
+            bus_error: self.bus_error.clone(),
+        }
+    }
+}
+
+impl<SPI, CS> Copy for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8> + Copy,
+    SPI::Error: Copy,
+    CS: Copy,
+{
+}
+
+impl<SPI, CS> WriteOnlyDataCommand for ThreeWireSPIInterface<SPI, CS>
+where
+    SPI: spi::Write<u8>,
+    CS: OutputPin,
+{
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.send(false, cmd)
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.send(true, buf)
+    }
+}
@@ -0,0 +1,269 @@
+//! Drawing in grayscale or RGB colors.
+//!
+//! [`Threshold`] wraps a [`DrawTarget`] with [`BinaryColor`] pixels, such as a display in
+//! [buffered graphics mode](crate::mode::BufferedGraphicsMode), and accepts colors which can be
+//! converted to [`Gray8`], such as `Gray8` itself or `Rgb565`. Pixels at least as bright as the
+//! threshold are drawn as `BinaryColor::On`, so code shared with color displays can draw to this
+//! one unchanged.
+//!
+//! Images with shades of gray can be [dithered](Dither) to keep the shades visible on the 1 bit
+//! per pixel display.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::Rgb565,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle},
+//! };
+//! use ssd1306::{prelude::*, threshold::Threshold, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! let mut color = Threshold::<_, Rgb565>::new(&mut display, 64);
+//!
+//! Circle::new(Point::new(40, 8), 48)
+//!     .into_styled(PrimitiveStyle::with_stroke(Rgb565::YELLOW, 2))
+//!     .draw(&mut color)
+//!     .unwrap();
+//!
+//! display.flush().unwrap();
+//! ```
+
+use core::{iter, marker::PhantomData};
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::{BinaryColor, Gray8, GrayColor, PixelColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Widest area which can be drawn with [`Dither::FloydSteinberg`]
+const MAX_DIFFUSION_WIDTH: usize = 128;
+
+/// 4x4 Bayer matrix, the order in which the pixels of a block are turned on as the luminance
+/// rises
+const BAYER: [[i16; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// How shades of gray are drawn by a [`Threshold`].
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use embedded_graphics::{
+///     image::{Image, ImageRaw},
+///     pixelcolor::Gray8,
+///     prelude::*,
+/// };
+/// use ssd1306::{
+///     prelude::*,
+///     threshold::{Dither, Threshold},
+///     Ssd1306,
+/// };
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+///
+/// // A gradient from black to white
+/// let mut data = [0; 64 * 8];
+/// for (i, luma) in data.iter_mut().enumerate() {
+///     *luma = (i % 64 * 4) as u8;
+/// }
+/// let gradient = ImageRaw::<Gray8>::new(&data, 64);
+///
+/// let mut gray = Threshold::new(&mut display, 128);
+/// gray.set_dither(Dither::FloydSteinberg);
+///
+/// Image::new(&gradient, Point::new(32, 28)).draw(&mut gray).unwrap();
+///
+/// display.flush().unwrap();
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Dither {
+    /// Every pixel is compared with the threshold
+    None,
+    /// The threshold is varied in a 4x4 pattern, which is fast and gives the same result for a
+    /// pixel however it's drawn
+    Ordered,
+    /// The difference between the luminance of each pixel and the color drawn is spread to the
+    /// neighbouring pixels, which looks best for photos.
+    ///
+    /// Only images and other areas drawn in order, up to 128 pixels wide, are diffused. Other
+    /// pixels use [`Dither::Ordered`].
+    FloydSteinberg,
+}
+
+/// A [`DrawTarget`] which converts colors to [`BinaryColor`] by comparing their luminance with a
+/// threshold.
+///
+/// See the [module documentation](crate::threshold) for an example.
+#[derive(Debug)]
+pub struct Threshold<'a, T, C> {
+    target: &'a mut T,
+    threshold: u8,
+    dither: Dither,
+    color: PhantomData<C>,
+}
+
+impl<'a, T, C> Threshold<'a, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    /// Draw to `target`, turning on the pixels with a luminance of at least `threshold`, from
+    /// `0` to `255`. Colors aren't dithered until [`set_dither`](Threshold::set_dither) is
+    /// called.
+    pub fn new(target: &'a mut T, threshold: u8) -> Self {
+        Self {
+            target,
+            threshold,
+            dither: Dither::None,
+            color: PhantomData,
+        }
+    }
+
+    /// Get the lowest luminance of the pixels which are turned on.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Set the lowest luminance of the pixels which are turned on.
+    pub fn set_threshold(&mut self, threshold: u8) {
+        self.threshold = threshold;
+    }
+
+    /// Get how shades of gray are drawn.
+    pub fn dither(&self) -> Dither {
+        self.dither
+    }
+
+    /// Set how shades of gray are drawn.
+    pub fn set_dither(&mut self, dither: Dither) {
+        self.dither = dither;
+    }
+}
+
+/// Get whether a pixel is turned on, without diffusing errors
+fn is_on(color: impl Into<Gray8>, pos: Point, threshold: u8, dither: Dither) -> bool {
+    let luma = i16::from(color.into().luma());
+
+    match dither {
+        Dither::None => luma >= threshold.into(),
+        Dither::Ordered | Dither::FloydSteinberg => {
+            let bias = BAYER[(pos.y & 3) as usize][(pos.x & 3) as usize] * 16 + 8 - 128;
+
+            luma + bias >= threshold.into()
+        }
+    }
+}
+
+impl<T, C> DrawTarget for Threshold<'_, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (threshold, dither) = (self.threshold, self.dither);
+
+        self.target
+            .draw_iter(pixels.into_iter().map(move |Pixel(pos, color)| {
+                Pixel(pos, BinaryColor::from(is_on(color, pos, threshold, dither)))
+            }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let (threshold, dither) = (self.threshold, self.dither);
+        let width = area.size.width as usize;
+        let top_left = area.top_left;
+
+        if width == 0 {
+            return Ok(());
+        }
+
+        if dither != Dither::FloydSteinberg || width > MAX_DIFFUSION_WIDTH {
+            return self.target.fill_contiguous(
+                area,
+                colors.into_iter().enumerate().map(move |(i, color)| {
+                    let pos = top_left + Point::new((i % width) as i32, (i / width) as i32);
+
+                    BinaryColor::from(is_on(color, pos, threshold, dither))
+                }),
+            );
+        }
+
+        // Errors of the pixels of this row and the next one, in 16ths, with a column of padding
+        // on both sides
+        let mut current = [0i16; MAX_DIFFUSION_WIDTH + 2];
+        let mut next = [0i16; MAX_DIFFUSION_WIDTH + 2];
+
+        self.target.fill_contiguous(
+            area,
+            colors.into_iter().enumerate().map(move |(i, color)| {
+                let x = i % width + 1;
+                if x == 1 && i != 0 {
+                    current = next;
+                    next = [0; MAX_DIFFUSION_WIDTH + 2];
+                }
+
+                let luma = i16::from(color.into().luma()) + current[x] / 16;
+                let on = luma >= threshold.into();
+                let error = luma - if on { 255 } else { 0 };
+
+                current[x + 1] += error * 7;
+                next[x - 1] += error * 3;
+                next[x] += error * 5;
+                next[x + 1] += error;
+
+                BinaryColor::from(on)
+            }),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        if self.dither == Dither::None {
+            let color = BinaryColor::from(is_on(color, area.top_left, self.threshold, self.dither));
+
+            return self.target.fill_solid(area, color);
+        }
+
+        let pixels = area.size.width as usize * area.size.height as usize;
+
+        self.fill_contiguous(area, iter::repeat(color).take(pixels))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        if self.dither == Dither::None {
+            let color = BinaryColor::from(is_on(color, Point::zero(), self.threshold, self.dither));
+
+            return self.target.clear(color);
+        }
+
+        let area = self.bounding_box();
+        self.fill_solid(&area, color)
+    }
+}
+
+impl<T, C> Dimensions for Threshold<'_, T, C>
+where
+    T: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
@@ -0,0 +1,200 @@
+//! Several displays drawn to as one.
+//!
+//! [`Tiled`] presents displays in [buffered graphics mode](crate::mode::BufferedGraphicsMode)
+//! placed next to or below each other as a single [`DrawTarget`], for example two 128x64
+//! modules as a 256x64 banner. Each pixel is drawn to the display it falls on, and
+//! [`flush`](Tiled::flush) sends the changed parts of every display.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let left_interface = StubInterface;
+//! # let right_interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{Line, PrimitiveStyle},
+//! };
+//! use ssd1306::{
+//!     prelude::*,
+//!     tiled::{TileLayout, Tiled},
+//!     Ssd1306,
+//! };
+//!
+//! let mut left = Ssd1306::new(left_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! left.init().unwrap();
+//!
+//! let mut right = Ssd1306::new(right_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! right.init().unwrap();
+//!
+//! let mut banner = Tiled::new([left, right], TileLayout::Horizontal);
+//! assert_eq!(banner.size(), Size::new(256, 64));
+//!
+//! // Drawn across both displays
+//! Line::new(Point::new(0, 0), Point::new(255, 63))
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut banner)
+//!     .unwrap();
+//!
+//! banner.flush().unwrap();
+//! ```
+
+use core::{fmt, iter};
+
+use crate::{error::DriverError, mode::BufferedGraphicsMode, size::DisplaySize, Ssd1306};
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    pixelcolor::BinaryColor,
+    Pixel,
+};
+
+/// How the displays of a [`Tiled`] are placed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TileLayout {
+    /// The displays are placed next to each other, from left to right
+    Horizontal,
+    /// The displays are placed below each other, from top to bottom
+    Vertical,
+}
+
+/// Displays in buffered graphics mode drawn to as a single [`DrawTarget`].
+///
+/// See the [module documentation](crate::tiled) for an example.
+pub struct Tiled<DI, SIZE, const N: usize>
+where
+    SIZE: DisplaySize,
+{
+    displays: [Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>; N],
+    layout: TileLayout,
+}
+
+impl<DI, SIZE, const N: usize> fmt::Debug for Tiled<DI, SIZE, N>
+where
+    SIZE: DisplaySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tiled")
+            .field("layout", &self.layout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<DI, SIZE, const N: usize> Tiled<DI, SIZE, N>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Combine initialised displays, placed as described by `layout`.
+    ///
+    /// Each display keeps its own rotation and dimensions, and the displays are aligned to the
+    /// top or left edge.
+    pub fn new(
+        displays: [Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>; N],
+        layout: TileLayout,
+    ) -> Self {
+        Self { displays, layout }
+    }
+
+    /// Get the displays, in the order they are placed in.
+    pub fn displays(&self) -> &[Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>; N] {
+        &self.displays
+    }
+
+    /// Get the displays mutably, for example to change their brightness.
+    pub fn displays_mut(&mut self) -> &mut [Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>; N] {
+        &mut self.displays
+    }
+
+    /// Get the layout of the displays.
+    pub fn layout(&self) -> TileLayout {
+        self.layout
+    }
+
+    /// Release the displays.
+    pub fn release(self) -> [Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>; N] {
+        self.displays
+    }
+
+    /// Write out the changed parts of all displays.
+    pub fn flush(&mut self) -> Result<(), DriverError> {
+        self.displays
+            .iter_mut()
+            .try_for_each(|display| display.flush())
+    }
+
+    /// Get the display a point falls on, and the point relative to the display
+    fn locate(&self, point: Point) -> Option<(usize, Point)> {
+        let mut start = 0;
+
+        for (index, display) in self.displays.iter().enumerate() {
+            let (width, height) = display.dimensions();
+            let (length, along, across, extent) = match self.layout {
+                TileLayout::Horizontal => (width, point.x, point.y, height),
+                TileLayout::Vertical => (height, point.y, point.x, width),
+            };
+            let end = start + i32::from(length);
+
+            if (start..end).contains(&along) && (0..i32::from(extent)).contains(&across) {
+                let local = match self.layout {
+                    TileLayout::Horizontal => Point::new(point.x - start, point.y),
+                    TileLayout::Vertical => Point::new(point.x, point.y - start),
+                };
+
+                return Some((index, local));
+            }
+
+            start = end;
+        }
+
+        None
+    }
+}
+
+impl<DI, SIZE, const N: usize> DrawTarget for Tiled<DI, SIZE, N>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        pixels
+            .into_iter()
+            .try_for_each(|Pixel(pos, color)| match self.locate(pos) {
+                Some((index, local)) => {
+                    self.displays[index].draw_iter(iter::once(Pixel(local, color)))
+                }
+                None => Ok(()),
+            })
+    }
+}
+
+impl<DI, SIZE, const N: usize> OriginDimensions for Tiled<DI, SIZE, N>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        self.displays
+            .iter()
+            .map(|display| display.dimensions())
+            .fold(Size::zero(), |size, (width, height)| match self.layout {
+                TileLayout::Horizontal => Size::new(
+                    size.width + u32::from(width),
+                    size.height.max(height.into()),
+                ),
+                TileLayout::Vertical => Size::new(
+                    size.width.max(width.into()),
+                    size.height + u32::from(height),
+                ),
+            })
+    }
+}
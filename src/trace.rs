@@ -0,0 +1,25 @@
+//! Tracing of the bytes sent to the display, enabled by the `log` or `defmt` features.
+
+/// The target of the trace records
+#[allow(dead_code)]
+pub(crate) const TARGET: &str = module_path!();
+
+/// Trace a command byte sequence sent to the display.
+#[allow(unused_variables)]
+pub(crate) fn commands(data: &[u8]) {
+    #[cfg(feature = "log")]
+    log::trace!(target: TARGET, "ssd1306: command {:02x?}", data);
+
+    #[cfg(feature = "defmt")]
+    defmt::trace!("ssd1306: command {=[u8]:x}", data);
+}
+
+/// Trace a data transfer to the display's GDDRAM.
+#[allow(unused_variables)]
+pub(crate) fn data(data: &[u8]) {
+    #[cfg(feature = "log")]
+    log::trace!(target: TARGET, "ssd1306: data, {} bytes", data.len());
+
+    #[cfg(feature = "defmt")]
+    defmt::trace!("ssd1306: data, {=usize} bytes", data.len());
+}
@@ -0,0 +1,222 @@
+//! Animated transitions between frames.
+//!
+//! A [`Transition`] animates from the frame in the buffer of a display in
+//! [buffered graphics mode](crate::mode::BufferedGraphicsMode) to a new frame, for example when
+//! changing between the pages of a menu. The old frame is copied when the transition is created,
+//! after which the new frame is drawn to the display as usual. Each
+//! [`step`](Transition::step) then draws and flushes the next frame of the animation, or
+//! [`run`](Transition::run) plays the whole animation with a delay between the steps.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::{DelayStub, StubInterface};
+//! # let interface = StubInterface;
+//! # let mut delay = DelayStub;
+//! use ssd1306::{
+//!     prelude::*,
+//!     transition::{Direction, Effect, Transition},
+//!     Ssd1306,
+//! };
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // Draw and flush the first page...
+//!
+//! let mut transition = Transition::new(&display, Effect::Slide(Direction::Left), 8);
+//!
+//! display.clear_buffer();
+//! // Draw the second page...
+//!
+//! // Slide to the second page in 8 steps of 12 ms
+//! transition.run(&mut display, &mut delay, 12).unwrap();
+//! ```
+
+use crate::{
+    error::DriverError,
+    mode::{pixel_index, BufferedGraphicsMode},
+    rotation::DisplayRotation,
+    size::{DisplaySize, NewZeroed},
+    Ssd1306,
+};
+use display_interface::WriteOnlyDataCommand;
+use embedded_hal::blocking::delay::DelayMs;
+
+/// Direction a [`Transition`] moves in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    /// Towards the left edge of the display
+    Left,
+    /// Towards the right edge of the display
+    Right,
+    /// Towards the top edge of the display
+    Up,
+    /// Towards the bottom edge of the display
+    Down,
+}
+
+/// Animation of a [`Transition`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Effect {
+    /// The new frame is uncovered by an edge moving over the old frame in a direction
+    Wipe(Direction),
+    /// The new frame pushes the old frame off the display in a direction
+    Slide(Direction),
+    /// The pixels of the new frame appear in a scattered order
+    Dissolve,
+}
+
+/// Transition from the frame in the buffer of a display to a new frame.
+///
+/// See the [module documentation](crate::transition) for an example.
+#[derive(Debug, Clone)]
+pub struct Transition<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// The old frame, in the layout of the display memory
+    from: SIZE::Buffer,
+    /// The new frame, copied from the display by the first step
+    to: SIZE::Buffer,
+    rotation: DisplayRotation,
+    effect: Effect,
+    steps: u8,
+    step: u8,
+}
+
+impl<SIZE> Transition<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Start a transition from the frame currently in the buffer of `display`, with an
+    /// animation of `steps` frames. A transition with 0 steps shows the new frame at once.
+    ///
+    /// The new frame is drawn to the display after creating the transition, and is copied from
+    /// the display by the first step.
+    pub fn new<DI>(
+        display: &Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>,
+        effect: Effect,
+        steps: u8,
+    ) -> Self
+    where
+        DI: WriteOnlyDataCommand,
+    {
+        let mut from: SIZE::Buffer = NewZeroed::new_zeroed();
+        from.as_mut().copy_from_slice(display.buffer());
+
+        Self {
+            from,
+            to: NewZeroed::new_zeroed(),
+            rotation: display.rotation(),
+            effect,
+            steps: steps.max(1),
+            step: 0,
+        }
+    }
+
+    /// Get whether the new frame is fully shown.
+    pub fn is_finished(&self) -> bool {
+        self.step >= self.steps
+    }
+
+    /// Draw the next frame of the animation to `display` and flush it. Returns `true` while more
+    /// steps are left.
+    ///
+    /// The display must not be drawn to or rotated until the transition is finished.
+    pub fn step<DI>(
+        &mut self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>,
+    ) -> Result<bool, DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+    {
+        if self.is_finished() {
+            return Ok(false);
+        }
+
+        if self.step == 0 {
+            self.to.as_mut().copy_from_slice(display.buffer());
+        }
+
+        self.step += 1;
+
+        let (width, height) = display.dimensions();
+        let (width, height) = (u32::from(width), u32::from(height));
+        let (step, steps) = (u32::from(self.step), u32::from(self.steps));
+        let buffer = display.buffer_mut();
+
+        for y in 0..height {
+            for x in 0..width {
+                // Which frame the pixel comes from, and where in that frame
+                let (new, x_from, y_from) = match self.effect {
+                    Effect::Wipe(direction) => {
+                        let new = match direction {
+                            Direction::Left => x >= width - width * step / steps,
+                            Direction::Right => x < width * step / steps,
+                            Direction::Up => y >= height - height * step / steps,
+                            Direction::Down => y < height * step / steps,
+                        };
+
+                        (new, x, y)
+                    }
+                    Effect::Slide(direction) => {
+                        let dx = width * step / steps;
+                        let dy = height * step / steps;
+
+                        match direction {
+                            Direction::Left if x + dx >= width => (true, x + dx - width, y),
+                            Direction::Left => (false, x + dx, y),
+                            Direction::Right if x < dx => (true, x + width - dx, y),
+                            Direction::Right => (false, x - dx, y),
+                            Direction::Up if y + dy >= height => (true, x, y + dy - height),
+                            Direction::Up => (false, x, y + dy),
+                            Direction::Down if y < dy => (true, x, y + height - dy),
+                            Direction::Down => (false, x, y - dy),
+                        }
+                    }
+                    Effect::Dissolve => (dissolve_rank(x, y) < 256 * step / steps, x, y),
+                };
+
+                let frame = if new { &self.to } else { &self.from };
+                let (idx, bit) = pixel_index::<SIZE>(self.rotation, x_from, y_from);
+                let value = frame.as_ref()[idx] >> bit & 1;
+
+                let (idx, bit) = pixel_index::<SIZE>(self.rotation, x, y);
+                buffer[idx] = buffer[idx] & !(1 << bit) | (value << bit);
+            }
+        }
+
+        display.flush()?;
+
+        Ok(!self.is_finished())
+    }
+
+    /// Play the rest of the animation on `display`, waiting `step_ms` milliseconds after each
+    /// step.
+    pub fn run<DI, DELAY>(
+        &mut self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>,
+        delay: &mut DELAY,
+        step_ms: u8,
+    ) -> Result<(), DriverError>
+    where
+        DI: WriteOnlyDataCommand,
+        DELAY: DelayMs<u8>,
+    {
+        while self.step(display)? {
+            delay.delay_ms(step_ms);
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the order of a pixel in a dissolve, from 0 to 255, scattered over the display
+fn dissolve_rank(x: u32, y: u32) -> u32 {
+    let hash =
+        (x.wrapping_mul(0x9e37_79b9) ^ y.wrapping_mul(0x85eb_ca6b)).wrapping_mul(0xc2b2_ae35);
+
+    hash >> 24
+}
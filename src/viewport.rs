@@ -0,0 +1,125 @@
+//! Drawing to a part of the display.
+//!
+//! A [`Viewport`] is a [`DrawTarget`] for a rectangular area of a display in
+//! [buffered graphics mode](crate::mode::BufferedGraphicsMode). Its origin is the top left corner
+//! of the area, and everything drawn outside of the area is clipped, so each widget of a
+//! dashboard can be drawn without offsetting its coordinates. The changes are tracked by the
+//! display, so the next [`flush`](crate::Ssd1306::flush) sends them.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle, Rectangle},
+//! };
+//! use ssd1306::{prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! // The right half of the display
+//! let mut status = display.viewport(Rectangle::new(Point::new(64, 0), Size::new(64, 64)));
+//!
+//! // Drawn at (64, 0) on the display, with the parts left of the viewport clipped
+//! Circle::new(Point::new(-10, 0), 20)
+//!     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+//!     .draw(&mut status)
+//!     .unwrap();
+//!
+//! display.flush().unwrap();
+//! ```
+
+use core::fmt;
+
+use crate::{mode::BufferedGraphicsMode, size::DisplaySize, Ssd1306};
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// A rectangular area of a display in buffered graphics mode to draw to.
+///
+/// Created by [`Ssd1306::viewport`]. See the [module documentation](crate::viewport) for an
+/// example.
+pub struct Viewport<'a, DI, SIZE>
+where
+    SIZE: DisplaySize,
+{
+    display: &'a mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>,
+    area: Rectangle,
+}
+
+impl<DI, SIZE> fmt::Debug for Viewport<'_, DI, SIZE>
+where
+    SIZE: DisplaySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Viewport")
+            .field("area", &self.area)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Get a [`Viewport`] to draw to the `area` of the display, with coordinates relative to
+    /// the top left corner of the area.
+    pub fn viewport(&mut self, area: Rectangle) -> Viewport<'_, DI, SIZE> {
+        Viewport {
+            display: self,
+            area,
+        }
+    }
+}
+
+impl<DI, SIZE> Viewport<'_, DI, SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Get the area of the display this viewport draws to.
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<DI, SIZE> DrawTarget for Viewport<'_, DI, SIZE>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.area;
+
+        self.display.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(pos, color)| Pixel(pos + area.top_left, color))
+                .filter(|Pixel(pos, _color)| area.contains(*pos)),
+        )
+    }
+}
+
+impl<DI, SIZE> OriginDimensions for Viewport<'_, DI, SIZE>
+where
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        self.area.size
+    }
+}